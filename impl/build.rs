@@ -11,5 +11,6 @@ fn detect_nightly() {
 }
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(nightly)");
     detect_nightly();
 }
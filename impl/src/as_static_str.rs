@@ -0,0 +1,203 @@
+//! Implementation of an [`AsStaticStr`] derive macro.
+//!
+//! [`AsStaticStr`]: macro@crate::AsStaticStr
+
+use convert_case::{Case, Casing as _};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    ext::IdentExt as _, parse::ParseStream, spanned::Spanned as _, Error, Result,
+};
+
+use crate::rename;
+
+/// Expands an [`AsStaticStr`] derive macro.
+///
+/// [`AsStaticStr`]: macro@crate::AsStaticStr
+pub fn expand(input: &syn::DeriveInput, _: &str) -> Result<TokenStream> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`AsStaticStr` can only be derived for enums",
+        ));
+    };
+
+    let rename_all = match Attribute::parse(&input.attrs)? {
+        Some((_, Attribute::RenameAll(case))) => Some(case),
+        Some((attr_name, Attribute::Rename(lit))) => {
+            return Err(Error::new(
+                lit.span(),
+                format!(
+                    "`#[{attr_name}(rename = \"...\")]` can only be placed on a \
+                     variant, maybe you want to use `rename_all`?",
+                ),
+            ))
+        }
+        None => None,
+    };
+
+    let arms = data
+        .variants
+        .iter()
+        .map(|v| {
+            if !v.fields.is_empty() {
+                return Err(Error::new(
+                    v.fields.span(),
+                    "`AsStaticStr` can only be derived for enums without fields",
+                ));
+            }
+            let name = match Attribute::parse(&v.attrs)? {
+                Some((_, Attribute::Rename(lit))) => lit,
+                Some((attr_name, Attribute::RenameAll(_))) => {
+                    return Err(Error::new(
+                        v.span(),
+                        format!(
+                            "`#[{attr_name}(rename_all = \"...\")]` can only be \
+                             placed on an enum",
+                        ),
+                    ))
+                }
+                None => {
+                    let name = v.ident.unraw().to_string();
+                    let name = rename_all
+                        .map_or_else(|| name.clone(), |case| name.to_case(case));
+                    syn::LitStr::new(&name, v.ident.span())
+                }
+            };
+            let v_name = &v.ident;
+            let pattern = match &v.fields {
+                syn::Fields::Named(_) => quote! { {} },
+                syn::Fields::Unnamed(_) => quote! { () },
+                syn::Fields::Unit => quote! {},
+            };
+            Ok(quote! { Self::#v_name #pattern => #name, })
+        })
+        .collect::<Result<TokenStream>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = "Returns the name of this variant as a `&'static str`."]
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #arms
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::AsRef<str> for #name #ty_generics
+             #where_clause
+        {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for &'static str
+             #where_clause
+        {
+            #[inline]
+            fn from(value: #name #ty_generics) -> Self {
+                value.as_str()
+            }
+        }
+    })
+}
+
+/// Parsed `#[as_static_str(...)]` attribute, or the `rename_all`/`rename`
+/// argument of a `#[display(...)]` one.
+enum Attribute {
+    /// `#[as_static_str(rename_all = "...")]`, along with the parsed rule.
+    RenameAll(Case),
+
+    /// `#[as_static_str(rename = "...")]`.
+    Rename(syn::LitStr),
+}
+
+impl Attribute {
+    /// Parses the single `#[as_static_str(...)]` attribute among the given
+    /// ones, falling back to the single `#[display(rename_all = "...")]` or
+    /// `#[display(rename = "...")]` one, if any, along with the name of the
+    /// attribute it's parsed from.
+    fn parse(attrs: &[syn::Attribute]) -> Result<Option<(&'static str, Self)>> {
+        for attr_name in ["as_static_str", "display"] {
+            if let Some(parsed) = Self::parse_single(attrs, attr_name)? {
+                return Ok(Some((attr_name, parsed)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Parses the single `#[<attr_name>(...)]` attribute among the given ones,
+    /// if any, ignoring `#[display(...)]` attributes other than `rename_all` and
+    /// `rename` ones.
+    fn parse_single(attrs: &[syn::Attribute], attr_name: &str) -> Result<Option<Self>> {
+        let mut iter = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident(attr_name))
+            .filter_map(|attr| {
+                attr.parse_args_with(|input: ParseStream| {
+                    let is_rename = input
+                        .fork()
+                        .parse::<Ident>()
+                        .map_or(false, |key| key == "rename_all" || key == "rename");
+                    if attr_name == "display" && !is_rename {
+                        return input.parse::<TokenStream>().map(|_| None);
+                    }
+                    Self::parse_args(attr, input).map(Some)
+                })
+                .transpose()
+                .map(|parsed| parsed.map(|parsed| (attr, parsed)))
+            });
+        let parsed = iter.next().transpose()?;
+        if let Some((another, _)) = iter.next().transpose()? {
+            return Err(Error::new(another.span(), "Too many attributes specified"));
+        }
+        Ok(parsed.map(|(_, parsed)| parsed))
+    }
+
+    /// Parses arguments of a single `#[as_static_str(...)]` attribute.
+    fn parse_args(attr: &syn::Attribute, input: ParseStream) -> Result<Self> {
+        let key: Ident = input.parse().map_err(|_| {
+            Error::new(
+                attr.path.span(),
+                "The format for this attribute cannot be parsed. Correct format: \
+                 `#[as_static_str(rename_all = \"...\")]` or \
+                 `#[as_static_str(rename = \"...\")]`",
+            )
+        })?;
+        let parsed = match key.to_string().as_str() {
+            "rename_all" => {
+                input.parse::<syn::Token![=]>()?;
+                Self::RenameAll(rename::parse_rule(&input.parse()?)?)
+            }
+            "rename" => {
+                input.parse::<syn::Token![=]>()?;
+                Self::Rename(input.parse()?)
+            }
+            _ => {
+                return Err(Error::new(
+                    key.span(),
+                    format!(
+                        "Unknown `{key}` attribute argument. \
+                         Allowed arguments are: rename_all, rename",
+                    ),
+                ))
+            }
+        };
+        if !input.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                format!("`{key}` accepts a single string literal only"),
+            ));
+        }
+        Ok(parsed)
+    }
+}
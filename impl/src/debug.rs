@@ -0,0 +1,296 @@
+//! Implementation of a [`fmt::Debug`] derive macro.
+//!
+//! [`fmt::Debug`]: std::fmt::Debug
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt as _, parse::ParseStream, spanned::Spanned as _, Error, Result,
+};
+
+use crate::{
+    parsing,
+    utils::{self, HashMap, HashSet},
+};
+
+/// Allowed arguments of `#[debug]` attribute placed on a field.
+const ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS: &[&str] = &["skip", "redact", "fmt"];
+
+/// Placeholder printed instead of a `#[debug(redact)]` field's value.
+const REDACTED: &str = "[REDACTED]";
+
+/// Expands a [`fmt::Debug`] derive macro.
+///
+/// [`fmt::Debug`]: std::fmt::Debug
+pub fn expand(input: &syn::DeriveInput, _: &str) -> Result<TokenStream> {
+    check_no_attribute(&input.attrs)?;
+
+    let mut state = State {
+        type_params: input
+            .generics
+            .type_params()
+            .map(|t| t.ident.clone())
+            .collect(),
+        bounds: HashMap::default(),
+    };
+
+    let name = &input.ident;
+    let arms = match &input.data {
+        syn::Data::Struct(s) => state.expand_arm(&quote! { #name }, name, &s.fields)?,
+        syn::Data::Enum(e) => e
+            .variants
+            .iter()
+            .map(|v| {
+                check_no_attribute(&v.attrs)?;
+                let v_name = &v.ident;
+                state.expand_arm(&quote! { Self::#v_name }, v_name, &v.fields)
+            })
+            .collect::<Result<_>>()?,
+        syn::Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "`Debug` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = if state.bounds.is_empty() {
+        input.generics.clone()
+    } else {
+        let bounds = state.bounds.into_iter().map(|(ty, trait_names)| {
+            let trait_names = trait_names
+                .into_iter()
+                .map(|trait_name| format_ident!("{trait_name}"));
+            quote! { #ty: #(::core::fmt::#trait_names)+* }
+        });
+        utils::add_extra_where_clauses(&input.generics, quote! { where #(#bounds),* })
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            #[inline]
+            fn fmt(
+                &self,
+                _derive_more_debug_formatter: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                match *self {
+                    #arms
+                }
+            }
+        }
+    })
+}
+
+/// Errors on any `#[debug(...)]` attribute, as they're allowed on fields only.
+fn check_no_attribute(attrs: &[syn::Attribute]) -> Result<()> {
+    match attrs.iter().find(|attr| attr.path.is_ident("debug")) {
+        Some(attr) => Err(Error::new(
+            attr.span(),
+            "`#[debug(...)]` attribute can be placed on fields only, \
+             maybe you want to derive `DebugCustom`?",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Parsed field-level `#[debug(...)]` attribute.
+enum FieldAttribute {
+    /// `#[debug(skip)]`, leaving the field out of the output.
+    Skip,
+
+    /// `#[debug(redact)]`, printing a placeholder instead of the field's value.
+    Redact,
+
+    /// `#[debug("...")]` or `#[debug(fmt = "...")]`, with the field being the
+    /// only positional argument.
+    Fmt(syn::LitStr),
+}
+
+impl FieldAttribute {
+    /// Parses the single `#[debug(...)]` attribute of the given field, if any.
+    fn parse(field: &syn::Field) -> Result<Option<Self>> {
+        let mut iter = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("debug"))
+            .map(|attr| {
+                attr.parse_args_with(|input: ParseStream| Self::parse_args(attr, input))
+                    .map(|parsed| (attr, parsed))
+            });
+        let parsed = iter.next().transpose()?;
+        if let Some((another, _)) = iter.next().transpose()? {
+            return Err(Error::new(another.span(), "Too many attributes specified"));
+        }
+        Ok(parsed.map(|(_, parsed)| parsed))
+    }
+
+    /// Parses arguments of a single field-level `#[debug(...)]` attribute.
+    fn parse_args(attr: &syn::Attribute, input: ParseStream) -> Result<Self> {
+        let parsed = if input.peek(syn::LitStr) {
+            // `#[debug("...")]`
+            Self::Fmt(input.parse()?)
+        } else if input.peek(syn::Ident) {
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                // `#[debug(skip)]`
+                "skip" => Self::Skip,
+                // `#[debug(redact)]`
+                "redact" => Self::Redact,
+                // `#[debug(fmt = "...")]`
+                "fmt" => {
+                    input.parse::<syn::Token![=]>()?;
+                    Self::Fmt(input.parse()?)
+                }
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown `{key}` field attribute argument. \
+                             Allowed arguments are: {}",
+                            ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS.join(", "),
+                        ),
+                    ))
+                }
+            }
+        } else {
+            return Err(Error::new(
+                attr.path.span(),
+                "The format for this attribute cannot be parsed. Correct format: \
+                 `#[debug(\"...\")]`, `#[debug(skip)]` or `#[debug(redact)]`",
+            ));
+        };
+
+        if !input.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Field-level format doesn't accept any arguments, \
+                 as the field itself is its only argument",
+            ));
+        }
+        Ok(parsed)
+    }
+
+    /// Returns names of the formatting traits the field is required to
+    /// implement, if its value is printed at all.
+    fn trait_names(&self) -> Vec<&'static str> {
+        match self {
+            Self::Skip | Self::Redact => Vec::new(),
+            Self::Fmt(lit) => parsing::format_string(&lit.value())
+                .into_iter()
+                .flat_map(|f| f.formats)
+                .map(|f| f.spec.map_or(parsing::Type::Display, |s| s.ty).trait_name())
+                .collect(),
+        }
+    }
+}
+
+/// State of the [`fmt::Debug`] derive macro expansion.
+///
+/// [`fmt::Debug`]: std::fmt::Debug
+struct State {
+    /// Type parameters of the type the trait is derived for.
+    type_params: HashSet<Ident>,
+
+    /// Trait bounds inferred from the fields' types.
+    bounds: HashMap<syn::Type, HashSet<&'static str>>,
+}
+
+impl State {
+    /// Expands a single match arm destructuring and formatting the given
+    /// `fields` of a struct or a variant.
+    fn expand_arm(
+        &mut self,
+        path: &TokenStream,
+        ident: &Ident,
+        fields: &syn::Fields,
+    ) -> Result<TokenStream> {
+        let name = syn::LitStr::new(&ident.unraw().to_string(), ident.span());
+
+        let mut patterns = Vec::with_capacity(fields.len());
+        let mut entries = Vec::with_capacity(fields.len());
+        let mut exhaustive = true;
+        for (i, field) in fields.iter().enumerate() {
+            let binding = field.ident.clone().unwrap_or_else(|| format_ident!("_{i}"));
+            let attr = FieldAttribute::parse(field)?;
+
+            let trait_names = attr
+                .as_ref()
+                .map_or_else(|| vec!["Debug"], FieldAttribute::trait_names);
+            if !trait_names.is_empty() {
+                if let Some(ty) = utils::get_if_type_parameter_used_in_type(
+                    &self.type_params,
+                    &field.ty,
+                ) {
+                    self.bounds.entry(ty).or_default().extend(trait_names);
+                }
+            }
+
+            // Only fields whose values are printed need to be bound.
+            let pattern = match (&field.ident, &attr) {
+                (_, None | Some(FieldAttribute::Fmt(_))) => {
+                    Some(quote! { ref #binding })
+                }
+                (Some(_), _) => None,
+                (None, _) => Some(quote! { _ }),
+            };
+            patterns.extend(pattern);
+
+            let value = match &attr {
+                None => quote! { #binding },
+                Some(FieldAttribute::Fmt(lit)) => {
+                    quote! { &::core::format_args!(#lit, #binding) }
+                }
+                Some(FieldAttribute::Redact) => {
+                    quote! { &::core::format_args!(#REDACTED) }
+                }
+                Some(FieldAttribute::Skip) => {
+                    exhaustive = false;
+                    continue;
+                }
+            };
+            entries.push(match &field.ident {
+                Some(ident) => {
+                    let field_name =
+                        syn::LitStr::new(&ident.unraw().to_string(), ident.span());
+                    quote! { .field(#field_name, #value) }
+                }
+                None => quote! { .field(#value) },
+            });
+        }
+
+        let formatter = Ident::new("_derive_more_debug_formatter", Span::call_site());
+        Ok(match fields {
+            syn::Fields::Named(_) => {
+                let finish = if exhaustive {
+                    quote! { finish }
+                } else {
+                    quote! { finish_non_exhaustive }
+                };
+                quote! {
+                    #path { #(#patterns,)* .. } => #formatter
+                        .debug_struct(#name)
+                        #(#entries)*
+                        .#finish(),
+                }
+            }
+            syn::Fields::Unnamed(_) => {
+                let finish = if exhaustive {
+                    quote! { finish }
+                } else {
+                    quote! { finish_non_exhaustive }
+                };
+                quote! {
+                    #path(#(#patterns),*) => #formatter
+                        .debug_tuple(#name)
+                        #(#entries)*
+                        .#finish(),
+                }
+            }
+            syn::Fields::Unit => quote! {
+                #path => #formatter.write_str(#name),
+            },
+        })
+    }
+}
@@ -1,18 +1,54 @@
-use std::{fmt::Display, str::FromStr as _};
+use std::{fmt::Display, ops::Range, str::FromStr as _};
 
+use convert_case::{Case, Casing as _};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned, ToTokens as _};
 use syn::{
-    parse::Parser as _, punctuated::Punctuated, spanned::Spanned as _, Error, Result,
+    ext::IdentExt as _,
+    parse::{ParseStream, Parser as _},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    Error, Result,
 };
 
 use crate::{
-    parsing,
+    parsing, rename,
     utils::{self, HashMap, HashSet},
 };
 
-/// Allowed [`syn::MetaNameValue`] arguments of `#[display]` attribute.
-const ALLOWED_ATTRIBUTE_ARGUMENTS: &[&str] = &["fmt", "bound"];
+/// Allowed arguments of `#[display]` attribute.
+const ALLOWED_ATTRIBUTE_ARGUMENTS: &[&str] = &[
+    "fmt",
+    "alt",
+    "bound",
+    "rename_all",
+    "rename",
+    "delimiter",
+    "pad",
+    "discriminant",
+];
+
+/// Primitive integer types allowed in `#[repr(...)]` attribute of an enum.
+const REPR_INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+    "isize",
+];
+
+/// Allowed arguments of `#[display]` attribute placed on a field.
+const ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS: &[&str] = &[
+    "fmt", "with", "forward", "join", "some", "none", "bytes", "path",
+];
+
+/// Name of the special placeholder substituted with the display name of an
+/// enum variant.
+const VARIANT_PLACEHOLDER: &str = "_variant";
+
+/// Name of the special placeholder substituted with the name of a type.
+const TYPE_PLACEHOLDER: &str = "_type";
+
+/// Names of the [`std`] types formatted via `Path::display()` by default, as
+/// they don't implement `Display` themselves.
+const PATH_TYPES: &[&str] = &["Path", "PathBuf", "OsStr", "OsString"];
 
 /// Provides the hook to expand `#[derive(Display)]` into an implementation of `From`
 pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> Result<TokenStream> {
@@ -26,19 +62,22 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> Result<TokenStream>
         .map(|t| t.ident.clone())
         .collect();
 
-    let ParseResult {
-        arms,
-        bounds,
-        requires_helper,
-    } = State {
+    let state = State {
         trait_path,
         trait_attr,
         input,
         type_params,
-    }
-    .get_match_arms_and_extra_bounds()?;
+    };
+    let ParseResult {
+        arms,
+        bounds,
+        mut requires_helper,
+        predicates,
+    } = state.get_match_arms_and_extra_bounds()?;
+    let pad = state.find_pad_on_type()?;
+    requires_helper |= pad;
 
-    let generics = if !bounds.is_empty() {
+    let generics = if !bounds.is_empty() || !predicates.is_empty() {
         let bounds: Vec<_> = bounds
             .into_iter()
             .map(|(ty, trait_names)| {
@@ -50,7 +89,7 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> Result<TokenStream>
             })
             .collect();
         let where_clause = quote_spanned! { input.span()=>
-            where #(#bounds),*
+            where #(#bounds,)* #(#predicates),*
         };
         utils::add_extra_where_clauses(&input.generics, where_clause)
     } else {
@@ -65,6 +104,20 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> Result<TokenStream>
         TokenStream::new()
     };
 
+    let body = if pad {
+        pad_helper(quote! {
+            match *self {
+                #arms
+            }
+        })
+    } else {
+        quote! {
+            match *self {
+                #arms
+            }
+        }
+    };
+
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #trait_path for #name #ty_generics #where_clause {
@@ -72,9 +125,7 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> Result<TokenStream>
             fn fmt(&self, _derive_more_display_formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 #helper_struct
 
-                match *self {
-                    #arms
-                }
+                #body
             }
         }
     })
@@ -151,16 +202,94 @@ fn display_as_helper_struct() -> TokenStream {
         where
             F: ::core::ops::Fn(&mut ::core::fmt::Formatter) -> ::core::fmt::Result;
 
-        const _derive_more_DisplayAs_impl: () = {
-            impl<F> ::core::fmt::Display for _derive_more_DisplayAs<F>
-            where
-                F: ::core::ops::Fn(&mut ::core::fmt::Formatter) -> ::core::fmt::Result
-            {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                    (self.0)(f)
+        impl<F> ::core::fmt::Display for _derive_more_DisplayAs<F>
+        where
+            F: ::core::ops::Fn(&mut ::core::fmt::Formatter) -> ::core::fmt::Result
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                (self.0)(f)
+            }
+        }
+    }
+}
+
+/// Wraps the given `body` writing into `_derive_more_display_formatter`, so its
+/// output is padded and truncated according to the width, alignment, fill and
+/// precision of the formatter, the same way [`Formatter::pad()`] does.
+///
+/// As the output isn't collected into a `String` (to support `no_std` without
+/// `alloc`), the `body` is formatted twice: first to count the characters, and
+/// then to actually write them out between the padding. The alternate flag of
+/// the formatter is forwarded to the `body`, so it's respected by the
+/// `#[display(alt = "...")]` attribute.
+///
+/// Requires the `DisplayAs` helper struct.
+///
+/// [`Formatter::pad()`]: std::fmt::Formatter::pad
+fn pad_helper(body: TokenStream) -> TokenStream {
+    quote! {
+        struct _derive_more_Truncate<'a, 'b> {
+            formatter: ::core::option::Option<&'a mut ::core::fmt::Formatter<'b>>,
+            remaining: usize,
+        }
+
+        impl ::core::fmt::Write for _derive_more_Truncate<'_, '_> {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                let end = s
+                    .char_indices()
+                    .nth(self.remaining)
+                    .map_or(s.len(), |(i, _)| i);
+                self.remaining -= s[..end].chars().count();
+                match &mut self.formatter {
+                    ::core::option::Option::Some(f) => f.write_str(&s[..end]),
+                    ::core::option::Option::None => ::core::result::Result::Ok(()),
                 }
             }
+        }
+
+        let _derive_more_display_value =
+            _derive_more_DisplayAs(|_derive_more_display_formatter| #body);
+        // The alternate flag is forwarded, so the `body` respects it.
+        let alternate = _derive_more_display_formatter.alternate();
+        let write_value = |w: &mut dyn ::core::fmt::Write| {
+            if alternate {
+                ::core::write!(w, "{:#}", _derive_more_display_value)
+            } else {
+                ::core::write!(w, "{}", _derive_more_display_value)
+            }
+        };
+        let limit = _derive_more_display_formatter.precision().unwrap_or(usize::MAX);
+
+        let mut counter = _derive_more_Truncate {
+            formatter: ::core::option::Option::None,
+            remaining: limit,
+        };
+        write_value(&mut counter)?;
+        let len = limit - counter.remaining;
+
+        let padding = _derive_more_display_formatter
+            .width()
+            .map_or(0, |width| width.saturating_sub(len));
+        let (pre, post) = match _derive_more_display_formatter.align() {
+            ::core::option::Option::Some(::core::fmt::Alignment::Right) => (padding, 0),
+            ::core::option::Option::Some(::core::fmt::Alignment::Center) => {
+                (padding / 2, (padding + 1) / 2)
+            }
+            _ => (0, padding),
         };
+        let fill = _derive_more_display_formatter.fill();
+
+        for _ in 0..pre {
+            ::core::fmt::Write::write_char(&mut *_derive_more_display_formatter, fill)?;
+        }
+        write_value(&mut _derive_more_Truncate {
+            formatter: ::core::option::Option::Some(&mut *_derive_more_display_formatter),
+            remaining: limit,
+        })?;
+        for _ in 0..post {
+            ::core::fmt::Write::write_char(&mut *_derive_more_display_formatter, fill)?;
+        }
+        ::core::result::Result::Ok(())
     }
 }
 
@@ -173,6 +302,8 @@ struct ParseResult {
     bounds: HashMap<syn::Type, HashSet<syn::TraitBound>>,
     /// `true` if the Display impl requires the `DisplayAs` helper struct.
     requires_helper: bool,
+    /// Custom `where` clause predicates specified via `bound(...)` attribute.
+    predicates: Vec<syn::WherePredicate>,
 }
 
 struct State<'a, 'b> {
@@ -185,13 +316,13 @@ struct State<'a, 'b> {
 impl<'a, 'b> State<'a, 'b> {
     fn get_proper_fmt_syntax(&self) -> impl Display {
         format!(
-            r#"Proper syntax: #[{}(fmt = "My format", "arg1", "arg2")]"#,
+            r#"Proper syntax: #[{}("My format", arg1, arg2)]"#,
             self.trait_attr,
         )
     }
     fn get_proper_bound_syntax(&self) -> impl Display {
         format!(
-            "Proper syntax: #[{}(bound = \"T, U: Trait1 + Trait2, V: Trait3\")]",
+            "Proper syntax: #[{}(bound(T: Trait1 + Trait2, U: Trait3))]",
             self.trait_attr,
         )
     }
@@ -215,70 +346,657 @@ impl<'a, 'b> State<'a, 'b> {
             }
         }
     }
-    fn find_meta(
+    /// Parses all the `#[<trait_attr>(...)]` attributes out of the given ones.
+    fn parse_attributes(&self, attrs: &[syn::Attribute]) -> Result<Vec<Attribute>> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident(self.trait_attr))
+            .filter(|attr| {
+                // `#[display]` and `#[display = "..."]` are not ours to handle.
+                !matches!(
+                    attr.parse_meta(),
+                    Ok(syn::Meta::Path(_) | syn::Meta::NameValue(_)),
+                )
+            })
+            .map(|attr| {
+                attr.parse_args_with(|input: ParseStream| {
+                    self.parse_attribute(attr, input)
+                })
+            })
+            .collect()
+    }
+    fn parse_attribute(
+        &self,
+        attr: &syn::Attribute,
+        input: ParseStream,
+    ) -> Result<Attribute> {
+        // `#[display("...", args...)]`
+        if input.peek(syn::LitStr) {
+            let lit = input.parse()?;
+            let args = if input.is_empty() {
+                Vec::new()
+            } else {
+                input.parse::<syn::Token![,]>()?;
+                Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?
+                    .into_iter()
+                    .map(FmtArgument::from_expr)
+                    .collect()
+            };
+            return Ok(Attribute::Fmt(FmtAttribute {
+                lit,
+                args,
+                span: attr.span(),
+            }));
+        }
+
+        // `#[display(bound(...))]`
+        if input.peek(syn::Ident)
+            && input.peek2(syn::token::Paren)
+            && input.fork().parse::<Ident>()? == "bound"
+        {
+            input.parse::<Ident>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let predicates = Punctuated::parse_terminated(&content)
+                .map_err(|e| Error::new(e.span(), self.get_proper_bound_syntax()))?;
+            if predicates.is_empty() {
+                return Err(Error::new(
+                    attr.path.span(),
+                    format!("No bounds specified. {}", self.get_proper_bound_syntax()),
+                ));
+            }
+            if !input.is_empty() {
+                return Err(Error::new(input.span(), self.get_proper_bound_syntax()));
+            }
+            return Ok(Attribute::Bound(BoundAttribute::Predicates(predicates)));
+        }
+
+        // `#[display(pad)]` or `#[display(discriminant)]`
+        if input.peek(syn::Ident) && !input.peek2(syn::Token![=]) {
+            let key = input.fork().parse::<Ident>()?;
+            if key == "pad" || key == "discriminant" {
+                input.parse::<Ident>()?;
+                if !input.is_empty() {
+                    return Err(Error::new(
+                        input.span(),
+                        format!("`{key}` doesn't accept any arguments"),
+                    ));
+                }
+                return Ok(if key == "pad" {
+                    Attribute::Pad(key)
+                } else {
+                    Attribute::Discriminant(key)
+                });
+            }
+        }
+
+        if !(input.peek(syn::Ident) && input.peek2(syn::Token![=])) {
+            // If the given attribute is neither a format string nor
+            // `MetaNameValue`, it most likely implies that the user is writing
+            // an incorrect format. For example:
+            // - `#[display()]`
+            // - `#[display(foo)]`
+            return Err(Error::new(
+                attr.path.span(),
+                format!(
+                    "The format for this attribute cannot be parsed. \
+                     Correct format: `#[{}(\"...\", args...)]`",
+                    self.trait_attr,
+                ),
+            ));
+        }
+
+        let key: Ident = input.parse()?;
+        if !ALLOWED_ATTRIBUTE_ARGUMENTS.iter().any(|attr| key == attr) {
+            return Err(Error::new(
+                key.span(),
+                format!(
+                    "Unknown `{key}` attribute argument. \
+                     Allowed arguments are: {}",
+                    ALLOWED_ATTRIBUTE_ARGUMENTS.join(", "),
+                ),
+            ));
+        }
+        input.parse::<syn::Token![=]>()?;
+
+        match key.to_string().as_str() {
+            // `#[display(fmt = "...", args...)]`
+            "fmt" => {
+                let lit = input
+                    .parse()
+                    .map_err(|e| Error::new(e.span(), self.get_proper_fmt_syntax()))?;
+                let args = if input.is_empty() {
+                    Vec::new()
+                } else {
+                    input.parse::<syn::Token![,]>()?;
+                    Punctuated::<syn::NestedMeta, syn::Token![,]>::parse_terminated(
+                        input,
+                    )?
+                    .into_iter()
+                    .map(|arg| match arg {
+                        syn::NestedMeta::Lit(syn::Lit::Str(s)) => {
+                            FmtArgument::from_lit_str(&s)
+                        }
+                        syn::NestedMeta::Meta(syn::Meta::Path(p)) => {
+                            Ok(FmtArgument::from_path(p))
+                        }
+                        _ => Err(Error::new(arg.span(), self.get_proper_fmt_syntax())),
+                    })
+                    .collect::<Result<_>>()?
+                };
+                Ok(Attribute::Fmt(FmtAttribute {
+                    lit,
+                    args,
+                    span: attr.span(),
+                }))
+            }
+            // `#[display(alt = "...", args...)]`
+            "alt" => {
+                let lit = input
+                    .parse()
+                    .map_err(|e| Error::new(e.span(), self.get_proper_fmt_syntax()))?;
+                let args = if input.is_empty() {
+                    Vec::new()
+                } else {
+                    input.parse::<syn::Token![,]>()?;
+                    Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?
+                        .into_iter()
+                        .map(FmtArgument::from_expr)
+                        .collect()
+                };
+                Ok(Attribute::Alt(FmtAttribute {
+                    lit,
+                    args,
+                    span: attr.span(),
+                }))
+            }
+            // `#[display(bound = "...")]`
+            "bound" => {
+                let bound = input.parse().map_err(|e| {
+                    Error::new(e.span(), self.get_proper_bound_syntax())
+                })?;
+                if !input.is_empty() {
+                    return Err(Error::new(
+                        input.span(),
+                        self.get_proper_bound_syntax(),
+                    ));
+                }
+                Ok(Attribute::Bound(BoundAttribute::Str(bound)))
+            }
+            // `#[display(delimiter = "...")]`
+            "delimiter" => {
+                let lit = input.parse()?;
+                if !input.is_empty() {
+                    return Err(Error::new(
+                        input.span(),
+                        "`delimiter` accepts a single string literal only",
+                    ));
+                }
+                Ok(Attribute::Delimiter(lit))
+            }
+            // `#[display(rename_all = "...")]` or `#[display(rename = "...")]`
+            "rename_all" | "rename" => {
+                let lit: syn::LitStr = input.parse()?;
+                if !input.is_empty() {
+                    return Err(Error::new(
+                        input.span(),
+                        format!("`{key}` accepts a single string literal only"),
+                    ));
+                }
+                if key == "rename" {
+                    return Ok(Attribute::Rename(lit));
+                }
+                let case = rename::parse_rule(&lit)?;
+                Ok(Attribute::RenameAll(lit, case))
+            }
+            _ => unreachable!(),
+        }
+    }
+    /// Finds the single `#[<trait_attr>(...)]` attribute selected by the given
+    /// `filter`, if any.
+    fn find_single<T>(
         &self,
         attrs: &[syn::Attribute],
-        meta_key: &str,
-    ) -> Result<Option<syn::Meta>> {
-        let mut metas = Vec::new();
-        for attr in attrs
+        filter: impl FnMut(Attribute) -> Option<(T, Span)>,
+    ) -> Result<Option<T>> {
+        let mut iter = self.parse_attributes(attrs)?.into_iter().filter_map(filter);
+        let found = iter.next();
+        match iter.next() {
+            None => Ok(found.map(|(found, _)| found)),
+            Some((_, span)) => Err(Error::new(span, "Too many attributes specified")),
+        }
+    }
+    /// Finds the single `#[<trait_attr>(...)]` attribute specifying a format
+    /// string, if any.
+    fn find_fmt(&self, attrs: &[syn::Attribute]) -> Result<Option<FmtAttribute>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Fmt(fmt) => {
+                let span = fmt.span;
+                Some((fmt, span))
+            }
+            _ => None,
+        })
+    }
+    /// Finds the single `#[<trait_attr>(alt = "...", args...)]` attribute, if
+    /// any.
+    fn find_alt(&self, attrs: &[syn::Attribute]) -> Result<Option<FmtAttribute>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Alt(fmt) => {
+                let span = fmt.span;
+                Some((fmt, span))
+            }
+            _ => None,
+        })
+    }
+    /// Wraps the given `fmt` expression into a branch on
+    /// `Formatter::alternate()`, if an alternate format string is specified via
+    /// `#[<trait_attr>(alt = "...")]` attribute, returning whether the
+    /// `DisplayAs` helper struct is required for it, along with its trait bounds.
+    fn apply_alt(
+        &self,
+        attrs: &[syn::Attribute],
+        fields: &syn::Fields,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        variant: Option<&syn::LitStr>,
+        fmt: TokenStream,
+    ) -> Result<(
+        TokenStream,
+        bool,
+        HashMap<syn::Type, HashSet<syn::TraitBound>>,
+    )> {
+        let Some(alt) = self.find_alt(attrs)? else {
+            return Ok((fmt, false, HashMap::default()));
+        };
+        let (alt_fmt, requires_helper) =
+            self.parse_fmt(&alt, fields, field_attrs, variant)?;
+        let bounds = self.get_used_type_params_bounds(fields, field_attrs, &alt);
+        Ok((
+            quote! {
+                if _derive_more_display_formatter.alternate() {
+                    #alt_fmt
+                } else {
+                    #fmt
+                }
+            },
+            requires_helper,
+            bounds,
+        ))
+    }
+    /// Finds the single `#[<trait_attr>(bound...)]` attribute, if any.
+    fn find_bound(&self, attrs: &[syn::Attribute]) -> Result<Option<BoundAttribute>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Bound(bound) => {
+                let span = bound.span();
+                Some((bound, span))
+            }
+            _ => None,
+        })
+    }
+    /// Finds the single `#[<trait_attr>(discriminant)]` attribute, if any.
+    fn find_discriminant(&self, attrs: &[syn::Attribute]) -> Result<Option<Ident>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Discriminant(discriminant) => {
+                let span = discriminant.span();
+                Some((discriminant, span))
+            }
+            _ => None,
+        })
+    }
+    /// Returns the expression formatting the discriminant of the given variant,
+    /// if either the variant or the whole enum is marked with
+    /// `#[<trait_attr>(discriminant)]` attribute. Variants having their own
+    /// format string or name aren't affected by the enum-level attribute.
+    fn discriminant_fmt(
+        &self,
+        e: &syn::DataEnum,
+        v: &syn::Variant,
+        enum_discriminant: Option<&Ident>,
+    ) -> Result<Option<TokenStream>> {
+        let customized =
+            self.find_fmt(&v.attrs)?.is_some() || self.find_rename(&v.attrs)?.is_some();
+        let discriminant = match self.find_discriminant(&v.attrs)? {
+            Some(discriminant) if customized => {
+                return Err(Error::new(
+                    discriminant.span(),
+                    format!(
+                        "`#[{}(discriminant)]` cannot be used along with a format \
+                         string or `rename`",
+                        self.trait_attr,
+                    ),
+                ))
+            }
+            Some(discriminant) => discriminant,
+            None => match enum_discriminant {
+                Some(discriminant) if !customized => discriminant.clone(),
+                _ => return Ok(None),
+            },
+        };
+        if e.variants
             .iter()
-            .filter(|attr| attr.path.is_ident(self.trait_attr))
+            .any(|v| !matches!(v.fields, syn::Fields::Unit))
         {
-            let meta = attr.parse_meta()?;
-            let syn::Meta::List(meta_list) = &meta else {
-                continue;
-            };
+            return Err(Error::new(
+                discriminant.span(),
+                format!(
+                    "`#[{}(discriminant)]` can only be used in enums without fields",
+                    self.trait_attr,
+                ),
+            ));
+        }
 
-            let Some(syn::NestedMeta::Meta(syn::Meta::NameValue(meta_nv))) =
-                meta_list.nested.first() else {
-                    // If the given attribute is not `MetaNameValue`, it most likely implies that
-                    // the user is writing an incorrect format. For example:
-                    // - `#[display()]`
-                    // - `#[display("foo")]`
-                    // - `#[display(foo)]`
+        let trait_path = self.trait_path;
+        let repr = find_repr(&self.input.attrs)?;
+        let v_name = &v.ident;
+        Ok(Some(quote! {
+            #trait_path::fmt(&(Self::#v_name as #repr), _derive_more_display_formatter)
+        }))
+    }
+    /// Finds the single `#[<trait_attr>(pad)]` attribute, if any.
+    fn find_pad(&self, attrs: &[syn::Attribute]) -> Result<Option<Ident>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Pad(pad) => {
+                let span = pad.span();
+                Some((pad, span))
+            }
+            _ => None,
+        })
+    }
+    /// Checks whether the whole type is marked with `#[<trait_attr>(pad)]`
+    /// attribute, erroring if any of its variants is marked instead.
+    fn find_pad_on_type(&self) -> Result<bool> {
+        if let syn::Data::Enum(e) = &self.input.data {
+            for v in &e.variants {
+                if let Some(pad) = self.find_pad(&v.attrs)? {
                     return Err(Error::new(
-                        meta.span(),
+                        pad.span(),
                         format!(
-                            "The format for this attribute cannot be parsed. \
-                             Correct format: `#[{}({meta_key} = \"...\")]`",
+                            "`#[{}(pad)]` can be placed on a whole type only",
                             self.trait_attr,
                         ),
                     ));
-                };
-            if !ALLOWED_ATTRIBUTE_ARGUMENTS
+                }
+            }
+        }
+        Ok(self.find_pad(&self.input.attrs)?.is_some())
+    }
+    /// Finds the single `#[<trait_attr>(rename_all = "...")]` attribute, if
+    /// any, along with its span.
+    fn find_rename_all(
+        &self,
+        attrs: &[syn::Attribute],
+    ) -> Result<Option<(Case, Span)>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::RenameAll(lit, case) => Some(((case, lit.span()), lit.span())),
+            _ => None,
+        })
+    }
+    /// Finds the single `#[<trait_attr>(delimiter = "...")]` attribute, if any.
+    fn find_delimiter(&self, attrs: &[syn::Attribute]) -> Result<Option<syn::LitStr>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Delimiter(lit) => {
+                let span = lit.span();
+                Some((lit, span))
+            }
+            _ => None,
+        })
+    }
+    /// Finds the single `#[<trait_attr>(delimiter = "...")]` attribute, if any,
+    /// erroring if it's used along with a format string.
+    fn find_delimiter_without_fmt(
+        &self,
+        attrs: &[syn::Attribute],
+    ) -> Result<Option<syn::LitStr>> {
+        let delimiter = self.find_delimiter(attrs)?;
+        if let Some(delimiter) = &delimiter {
+            if self.find_fmt(attrs)?.is_some() {
+                return Err(Error::new(
+                    delimiter.span(),
+                    format!(
+                        "`#[{}(delimiter = \"...\")]` cannot be used along with \
+                         a format string",
+                        self.trait_attr,
+                    ),
+                ));
+            }
+        }
+        Ok(delimiter)
+    }
+    /// Finds the single `#[<trait_attr>(rename = "...")]` attribute, if any.
+    fn find_rename(&self, attrs: &[syn::Attribute]) -> Result<Option<syn::LitStr>> {
+        self.find_single(attrs, |attr| match attr {
+            Attribute::Rename(lit) => {
+                let span = lit.span();
+                Some((lit, span))
+            }
+            _ => None,
+        })
+    }
+    /// Returns the name to be displayed for a type or variant without fields,
+    /// respecting its `#[<trait_attr>(rename = "...")]` attribute and the
+    /// `rename_all` rule of the enum.
+    fn get_name(
+        &self,
+        attrs: &[syn::Attribute],
+        ident: &Ident,
+        fields: &syn::Fields,
+        rename_all: Option<Case>,
+    ) -> Result<syn::LitStr> {
+        if let Some((_, span)) = self.find_rename_all(attrs)? {
+            return Err(Error::new(
+                span,
+                format!(
+                    "`#[{}(rename_all = \"...\")]` can only be placed on an enum",
+                    self.trait_attr,
+                ),
+            ));
+        }
+        let rename = self.find_rename(attrs)?;
+        if let Some(rename) = &rename {
+            if (!fields.is_empty() || self.find_fmt(attrs)?.is_some())
+                && !self.refers_variant_name(attrs)?
+            {
+                return Err(Error::new(
+                    rename.span(),
+                    format!(
+                        "`#[{}(rename = \"...\")]` can only be placed on a struct or a \
+                         variant without fields and a format string",
+                        self.trait_attr,
+                    ),
+                ));
+            }
+        }
+        Ok(rename.unwrap_or_else(|| {
+            let name = ident.unraw().to_string();
+            let name =
+                rename_all.map_or_else(|| name.clone(), |case| name.to_case(case));
+            syn::LitStr::new(&name, ident.span())
+        }))
+    }
+    /// Parses field-level `#[<trait_attr>(...)]` attributes of the given
+    /// fields, keyed by the names the fields are bound to in match arms.
+    fn parse_field_attributes(
+        &self,
+        fields: &syn::Fields,
+    ) -> Result<HashMap<String, FieldAttribute>> {
+        let mut field_attrs = HashMap::default();
+        for (i, field) in fields.iter().enumerate() {
+            let mut iter = field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident(self.trait_attr))
+                .map(|attr| {
+                    attr.parse_args_with(|input: ParseStream| {
+                        self.parse_field_attribute(attr, input)
+                    })
+                    .map(|parsed| (attr, parsed))
+                });
+            let binding = field
+                .ident
+                .as_ref()
+                .map_or_else(|| format!("_{i}"), ToString::to_string);
+            let Some(field_attr) = iter.next().transpose()? else {
+                if self.trait_attr == "display"
+                    && cfg!(feature = "std")
+                    && is_path_type(&field.ty)
+                {
+                    field_attrs.insert(binding, FieldAttribute::Path(field.ty.span()));
+                }
+                continue;
+            };
+            if let Some((another, _)) = iter.next().transpose()? {
+                return Err(Error::new(
+                    another.span(),
+                    "Too many attributes specified",
+                ));
+            }
+
+            field_attrs.insert(binding, field_attr.1);
+        }
+        Ok(field_attrs)
+    }
+    fn parse_field_attribute(
+        &self,
+        attr: &syn::Attribute,
+        input: ParseStream,
+    ) -> Result<FieldAttribute> {
+        let attribute = if input.peek(syn::LitStr) {
+            // `#[display("...")]`
+            FieldAttribute::Fmt(input.parse()?)
+        } else if input.peek(syn::Ident) && !input.peek2(syn::Token![=]) {
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                // `#[display(forward)]`
+                "forward" => FieldAttribute::Forward(key),
+                // `#[lower_hex(bytes)]` or `#[upper_hex(bytes)]`
+                "bytes" => {
+                    if !matches!(self.trait_attr, "lower_hex" | "upper_hex") {
+                        return Err(Error::new(
+                            key.span(),
+                            format!(
+                                "`#[{}(bytes)]` can only be used in `LowerHex` and \
+                                 `UpperHex` derives",
+                                self.trait_attr,
+                            ),
+                        ));
+                    }
+                    FieldAttribute::Bytes {
+                        span: key.span(),
+                        upper: self.trait_attr == "upper_hex",
+                    }
+                }
+                // `#[display(path)]`
+                "path" => {
+                    if self.trait_attr != "display" {
+                        return Err(Error::new(
+                            key.span(),
+                            format!(
+                                "`#[{}(path)]` can only be used in `Display` derive",
+                                self.trait_attr,
+                            ),
+                        ));
+                    }
+                    if !cfg!(feature = "std") {
+                        return Err(Error::new(
+                            key.span(),
+                            "`#[display(path)]` requires the `std` feature",
+                        ));
+                    }
+                    FieldAttribute::Path(key.span())
+                }
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown `{key}` field attribute argument. \
+                             Allowed arguments are: {}",
+                            ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS.join(", "),
+                        ),
+                    ))
+                }
+            }
+        } else if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+            let key: Ident = input.parse()?;
+            if !ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS
                 .iter()
-                .any(|attr| meta_nv.path.is_ident(attr))
+                .any(|attr| key == attr)
             {
                 return Err(Error::new(
-                    meta_nv.path.span(),
+                    key.span(),
                     format!(
-                        "Unknown `{}` attribute argument. \
+                        "Unknown `{key}` field attribute argument. \
                          Allowed arguments are: {}",
-                        meta_nv.path.to_token_stream(),
-                        ALLOWED_ATTRIBUTE_ARGUMENTS
-                            .iter()
-                            .fold(None, |acc, key| acc.map_or_else(
-                                || Some(key.to_string()),
-                                |acc| Some(format!("{acc}, {key}"))
-                            ))
-                            .unwrap_or_default(),
+                        ALLOWED_FIELD_ATTRIBUTE_ARGUMENTS.join(", "),
                     ),
                 ));
             }
-
-            if meta_nv.path.is_ident(meta_key) {
-                metas.push(meta);
+            input.parse::<syn::Token![=]>()?;
+
+            match key.to_string().as_str() {
+                // `#[display(fmt = "...")]`
+                "fmt" => FieldAttribute::Fmt(input.parse()?),
+                // `#[display(with = path::to::fn)]`
+                "with" => FieldAttribute::With(input.parse()?),
+                // `#[display(join = "...")]`
+                "join" => FieldAttribute::Join(input.parse()?),
+                // `#[display(some = "...", none = "...")]`
+                "some" | "none" => {
+                    let span = key.span();
+                    let (mut some, mut none) = (None, None);
+                    let mut key = key;
+                    loop {
+                        let slot = if key == "some" { &mut some } else { &mut none };
+                        if slot.replace(input.parse::<syn::LitStr>()?).is_some() {
+                            return Err(Error::new(
+                                key.span(),
+                                format!("Duplicated `{key}` field attribute argument"),
+                            ));
+                        }
+                        if input.is_empty() {
+                            break;
+                        }
+                        input.parse::<syn::Token![,]>()?;
+                        key = input.parse()?;
+                        if key != "some" && key != "none" {
+                            return Err(Error::new(
+                                key.span(),
+                                "Only `some` and `none` field attribute arguments \
+                                 can be combined",
+                            ));
+                        }
+                        input.parse::<syn::Token![=]>()?;
+                    }
+                    FieldAttribute::Option { span, some, none }
+                }
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "`{key}` field attribute argument doesn't accept a value"
+                        ),
+                    ))
+                }
             }
-        }
-
-        let mut iter = metas.into_iter();
-        let meta = iter.next();
-        if iter.next().is_none() {
-            Ok(meta)
         } else {
-            Err(Error::new(meta.span(), "Too many attributes specified"))
+            return Err(Error::new(
+                attr.path.span(),
+                format!(
+                    "The format for this attribute cannot be parsed. \
+                     Correct format: `#[{0}(\"...\")]`, \
+                     `#[{0}(with = path::to::fn)]`, `#[{0}(join = \"...\")]`, \
+                     `#[{0}(some = \"...\", none = \"...\")]` or `#[{0}(forward)]`",
+                    self.trait_attr,
+                ),
+            ));
+        };
+
+        if !input.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Field-level format doesn't accept any arguments, \
+                 as the field itself is its only argument",
+            ));
         }
+        Ok(attribute)
     }
     fn parse_meta_bounds(
         &self,
@@ -349,150 +1067,382 @@ impl<'a, 'b> State<'a, 'b> {
 
         Ok(bounds)
     }
-    fn parse_meta_fmt(
+    /// Checks whether the given [`FmtAttribute`] placed on a whole enum is an
+    /// affix spec, having a single placeholder for the inner variant display.
+    ///
+    /// Special `{_variant}` and `{_type}` placeholders aren't counted.
+    fn is_affix_fmt(&self, fmt: &FmtAttribute) -> Result<bool> {
+        let FmtAttribute { lit, args, .. } = fmt;
+        let expected_affix_usage = "outer `enum` `fmt` is an affix spec that expects no args and at most 1 placeholder for inner variant display";
+        if !args.is_empty() {
+            return Err(Error::new(args[0].expr.span(), expected_affix_usage));
+        }
+        let placeholders = Placeholder::parse_fmt_string(&lit.value())
+            .into_iter()
+            .filter(|p| !is_special_placeholder(&p.arg))
+            .collect::<Vec<_>>();
+        if placeholders.len() > 1
+            || placeholders
+                .first()
+                .map(|p| p.arg != Parameter::Positional(0))
+                .unwrap_or_default()
+        {
+            return Err(Error::new(lit.span(), expected_affix_usage));
+        }
+        Ok(placeholders.len() == 1)
+    }
+    /// Returns the value of the special `{_variant}` or `{_type}` placeholder
+    /// named `name`, if it's the one, given the display name of the variant
+    /// being formatted, if any.
+    fn special_placeholder(
         &self,
-        meta: &syn::Meta,
-        outer_enum: bool,
+        name: &str,
+        span: Span,
+        variant: Option<&syn::LitStr>,
+    ) -> Option<Result<syn::LitStr>> {
+        match name {
+            TYPE_PLACEHOLDER => Some(Ok(syn::LitStr::new(
+                &self.input.ident.unraw().to_string(),
+                span,
+            ))),
+            VARIANT_PLACEHOLDER => Some(
+                variant
+                    .map(|lit| syn::LitStr::new(&lit.value(), span))
+                    .ok_or_else(|| {
+                        Error::new(
+                            span,
+                            format!(
+                                "`{{{VARIANT_PLACEHOLDER}}}` placeholder can only be \
+                                 used in enums",
+                            ),
+                        )
+                    }),
+            ),
+            _ => None,
+        }
+    }
+    /// Checks whether the display name of a variant is referred to by the
+    /// special `{_variant}` placeholder in its own format strings or in the one
+    /// of the whole enum.
+    fn refers_variant_name(&self, attrs: &[syn::Attribute]) -> Result<bool> {
+        Ok([
+            self.find_fmt(attrs)?,
+            self.find_alt(attrs)?,
+            self.find_fmt(&self.input.attrs)?,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|fmt| {
+            Placeholder::parse_fmt_string(&fmt.lit.value())
+                .iter()
+                .any(|p| p.arg == Parameter::Named(VARIANT_PLACEHOLDER.into()))
+        }))
+    }
+    /// Renders the given [`FmtAttribute`] into a `write!` call, returning
+    /// whether the `DisplayAs` helper struct is required for it.
+    ///
+    /// The `variant` is the display name of the variant being formatted, if
+    /// any, substituted into the special `{_variant}` placeholder.
+    fn parse_fmt(
+        &self,
+        fmt: &FmtAttribute,
+        fields: &syn::Fields,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        variant: Option<&syn::LitStr>,
     ) -> Result<(TokenStream, bool)> {
-        let syn::Meta::List(list) = meta else {
-            return Err(Error::new(meta.span(), self.get_proper_fmt_syntax()));
-        };
+        let FmtAttribute { lit, args, span } = fmt;
+        let value = lit.value();
+        let named_spans = Placeholder::parse_named_ranges(&value)
+            .into_iter()
+            .map(|(name, range)| (name, lit_subspan(lit, range)))
+            .collect::<HashMap<_, _>>();
+        let named_span =
+            |name: &str| named_spans.get(name).copied().unwrap_or(lit.span());
 
-        match &list.nested[0] {
-            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
-                path,
-                lit: syn::Lit::Str(fmt),
-                ..
-            })) => match path {
-                op if op.segments.first().expect("path shouldn't be empty").ident
-                    == "fmt" =>
-                {
-                    let expected_affix_usage = "outer `enum` `fmt` is an affix spec that expects no args and at most 1 placeholder for inner variant display";
-                    let placeholders = Placeholder::parse_fmt_string(&fmt.value());
-                    if outer_enum {
-                        if list.nested.iter().skip(1).count() != 0 {
-                            return Err(Error::new(
-                                list.nested[1].span(),
-                                expected_affix_usage,
-                            ));
-                        }
-                        if placeholders.len() > 1
-                            || placeholders
-                                .first()
-                                .map(|p| p.arg != Parameter::Positional(0))
-                                .unwrap_or_default()
-                        {
-                            return Err(Error::new(
-                                list.nested[1].span(),
-                                expected_affix_usage,
-                            ));
-                        }
-                        if placeholders.len() == 1 {
-                            return Ok((quote_spanned! { fmt.span()=> #fmt }, true));
-                        }
+        let placeholders = Placeholder::parse_fmt_string(&lit.value());
+
+        if let Some(FieldAttribute::Forward(forward)) = field_attrs
+            .values()
+            .find(|attr| matches!(attr, FieldAttribute::Forward(_)))
+        {
+            return Err(Error::new(
+                forward.span(),
+                format!(
+                    "`#[{}(forward)]` cannot be used along with a format string",
+                    self.trait_attr,
+                ),
+            ));
+        }
+
+        let explicit_names: HashSet<_> = args
+            .iter()
+            .filter_map(|arg| arg.name.as_ref().map(ToString::to_string))
+            .collect();
+        let args = args.iter().map(|arg| {
+            let expr = &arg.expr;
+            match &arg.name {
+                Some(name) => quote_spanned! { *span=> #name = #expr, },
+                None => quote_spanned! { *span=> #expr, },
+            }
+        });
+
+        // Fields with a custom format are captured as a `DisplayAs` wrapper,
+        // which is only usable as an argument of a `Display` placeholder.
+        // Paths are captured as `path::Display` instead, supporting `Debug`
+        // and the formatting options as well.
+        let misused_field = placeholders.iter().find_map(|p| {
+            p.width
+                .iter()
+                .chain(&p.precision)
+                .chain((p.trait_name != "Display").then_some(&p.arg))
+                .find_map(|arg| match arg {
+                    Parameter::Named(i)
+                        if field_attrs.get(i).map_or(false, |attr| {
+                            !matches!(attr, FieldAttribute::Path(_))
+                        }) && !explicit_names.contains(i) =>
+                    {
+                        Some(i)
+                    }
+                    _ => None,
+                })
+        });
+        if let Some(field) = misused_field {
+            return Err(Error::new(
+                named_span(field),
+                format!(
+                    "Field `{field}` has a custom format, so it can only be \
+                     captured as `{{{field}}}`. Pass it as an explicit argument \
+                     to format it otherwise.",
+                ),
+            ));
+        }
+
+        let bindings = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                field
+                    .ident
+                    .as_ref()
+                    .map_or_else(|| format!("_{i}"), ToString::to_string)
+            })
+            .collect::<Vec<_>>();
+
+        let mut requires_helper = false;
+        let interpolated_args = placeholders
+            .into_iter()
+            .flat_map(|p| {
+                let map_argument = |arg| match arg {
+                    Parameter::Named(i) => Some(i),
+                    Parameter::Positional(_) => None,
+                };
+                map_argument(p.arg)
+                    .into_iter()
+                    .chain(p.width.and_then(map_argument))
+                    .chain(p.precision.and_then(map_argument))
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|ident| !explicit_names.contains(ident))
+            .map(|ident| {
+                let span = named_span(&ident);
+                if !bindings.contains(&ident) {
+                    if let Some(value) = self.special_placeholder(&ident, span, variant)
+                    {
+                        let ident = format_ident!("{ident}", span = span);
+                        let value = value?;
+                        return Ok(quote! { #ident = #value, });
                     }
-                    let args = list
-                        .nested
+                }
+                let field_attr = field_attrs.get(&ident);
+                let ident = format_ident!("{ident}", span = span);
+                if let Some(FieldAttribute::Path(span)) = field_attr {
+                    let path = path_display(&ident, *span);
+                    Ok(quote! { #ident = #path, })
+                } else if let Some(field_attr) = field_attr {
+                    requires_helper = true;
+                    let ty = &fields
                         .iter()
-                        .skip(1) // skip fmt = "..."
-                        .try_fold(TokenStream::new(), |args, arg| {
-                            let arg = match arg {
-                                syn::NestedMeta::Lit(syn::Lit::Str(s)) => s,
-                                syn::NestedMeta::Meta(syn::Meta::Path(i)) => {
-                                    return Ok(
-                                        quote_spanned! { list.span()=> #args #i, },
-                                    );
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        arg.span(),
-                                        self.get_proper_fmt_syntax(),
-                                    ))
-                                }
-                            };
-                            let arg: TokenStream =
-                                arg.parse().map_err(|e| Error::new(arg.span(), e))?;
-                            Ok(quote_spanned! { list.span()=> #args #arg, })
-                        })?;
-
-                    let interpolated_args = placeholders
-                        .into_iter()
-                        .flat_map(|p| {
-                            let map_argument = |arg| match arg {
-                                Parameter::Named(i) => Some(i),
-                                Parameter::Positional(_) => None,
-                            };
-                            map_argument(p.arg)
-                                .into_iter()
-                                .chain(p.width.and_then(map_argument))
-                                .chain(p.precision.and_then(map_argument))
-                        })
-                        .collect::<HashSet<_>>()
-                        .into_iter()
-                        .map(|ident| {
-                            let ident = format_ident!("{ident}", span = fmt.span());
-                            quote! { #ident = #ident, }
-                        })
-                        .collect::<TokenStream>();
-
-                    Ok((
-                        quote_spanned! { meta.span()=>
-                            write!(_derive_more_display_formatter, #fmt, #args #interpolated_args)
-                        },
-                        false,
-                    ))
+                        .zip(&bindings)
+                        .find(|(_, binding)| ident == binding)
+                        .map(|(field, _)| field)
+                        .expect("field attributes belong to existing fields")
+                        .ty;
+                    // Captured field is formatted as `Display`.
+                    let fmt = field_attr.fmt_expr(
+                        &ident,
+                        ty,
+                        &quote! { ::core::fmt::Display },
+                    );
+                    Ok(quote! {
+                        #ident = _derive_more_DisplayAs(|_derive_more_display_formatter| #fmt),
+                    })
+                } else {
+                    // Fields and other names (like constants or statics) are
+                    // captured implicitly, so the compiler reports unknown ones
+                    // at the exact placeholder, suggesting similar names.
+                    Ok(TokenStream::new())
                 }
-                _ => Err(Error::new(
-                    list.nested[0].span(),
-                    self.get_proper_fmt_syntax(),
-                )),
+            })
+            .collect::<Result<TokenStream>>()?;
+
+        Ok((
+            quote_spanned! { *span=>
+                write!(_derive_more_display_formatter, #lit, #(#args)* #interpolated_args)
             },
-            _ => Err(Error::new(
-                list.nested[0].span(),
-                self.get_proper_fmt_syntax(),
-            )),
-        }
+            requires_helper,
+        ))
+    }
+    fn infer_fmt(
+        &self,
+        fields: &syn::Fields,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        name: &syn::LitStr,
+        delimiter: Option<&syn::LitStr>,
+    ) -> Result<TokenStream> {
+        let trait_path = self.trait_path;
+        let fmt = self
+            .find_inferred_fields(fields, field_attrs, delimiter)?
+            .into_iter()
+            .map(|(ident, field)| {
+                if let Some(field_attr) = field_attrs.get(&ident.to_string()) {
+                    field_attr.fmt_expr(&ident, &field.ty, trait_path)
+                } else {
+                    quote! { #trait_path::fmt(#ident, _derive_more_display_formatter) }
+                }
+            })
+            .reduce(|fmt, next| {
+                quote! {
+                    #fmt?;
+                    _derive_more_display_formatter.write_str(#delimiter)?;
+                    #next
+                }
+            });
+        Ok(match fmt {
+            Some(fmt) => quote! { { #fmt } },
+            None => quote! { _derive_more_display_formatter.write_str(#name) },
+        })
     }
-    fn infer_fmt(&self, fields: &syn::Fields, name: &Ident) -> Result<TokenStream> {
+    /// Finds the fields to infer the format from, along with their bindings.
+    ///
+    /// These are all the fields if the `delimiter` is specified, or otherwise
+    /// either the only field, or the one marked with `#[display(forward)]`.
+    fn find_inferred_fields<'f>(
+        &self,
+        fields: &'f syn::Fields,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        delimiter: Option<&syn::LitStr>,
+    ) -> Result<Vec<(Ident, &'f syn::Field)>> {
         let fields = match fields {
-            syn::Fields::Unit => {
-                return Ok(quote! {
-                    _derive_more_display_formatter.write_str(stringify!(#name))
-                })
-            }
+            syn::Fields::Unit => return Ok(Vec::new()),
             syn::Fields::Named(fields) => &fields.named,
             syn::Fields::Unnamed(fields) => &fields.unnamed,
         };
-        if fields.is_empty() {
-            return Ok(quote! {
-                _derive_more_display_formatter.write_str(stringify!(#name))
-            });
-        } else if fields.len() > 1 {
+        let bindings = fields.iter().enumerate().map(|(i, field)| {
+            let binding = field.ident.clone().unwrap_or_else(|| format_ident!("_{i}"));
+            (binding, field)
+        });
+        if delimiter.is_some() {
+            if let Some(forward) = field_attrs
+                .values()
+                .find(|attr| matches!(attr, FieldAttribute::Forward(_)))
+            {
+                return Err(Error::new(
+                    forward.span(),
+                    format!(
+                        "`#[{}(forward)]` cannot be used along with a delimiter",
+                        self.trait_attr,
+                    ),
+                ));
+            }
+            return Ok(bindings.collect());
+        }
+        if fields.len() <= 1 {
+            return Ok(bindings.collect());
+        }
+
+        let mut forwarded = bindings.filter(|(binding, _)| {
+            matches!(
+                field_attrs.get(&binding.to_string()),
+                Some(FieldAttribute::Forward(_)),
+            )
+        });
+        let Some(field) = forwarded.next() else {
             return Err(Error::new(
                 fields.span(),
-                "Cannot automatically infer format for types with more than 1 field",
+                format!(
+                    "Cannot automatically infer format for types with more than 1 field, \
+                     consider specifying a format string, a delimiter or marking a single \
+                     field with `#[{}(forward)]`",
+                    self.trait_attr,
+                ),
+            ));
+        };
+        if let Some((another, _)) = forwarded.next() {
+            return Err(Error::new(
+                field_attrs[&another.to_string()].span(),
+                format!(
+                    "Only a single field can be marked with `#[{}(forward)]`",
+                    self.trait_attr,
+                ),
             ));
         }
-
-        let trait_path = self.trait_path;
-        if let Some(ident) = &fields.iter().next().as_ref().unwrap().ident {
-            Ok(quote! { #trait_path::fmt(#ident, _derive_more_display_formatter) })
-        } else {
-            Ok(quote! { #trait_path::fmt(_0, _derive_more_display_formatter) })
-        }
+        Ok(vec![field])
     }
     fn get_match_arms_and_extra_bounds(&self) -> Result<ParseResult> {
+        if !matches!(self.input.data, syn::Data::Enum(_)) {
+            if let Some(discriminant) = self.find_discriminant(&self.input.attrs)? {
+                return Err(Error::new(
+                    discriminant.span(),
+                    format!(
+                        "`#[{}(discriminant)]` can only be used in enums",
+                        self.trait_attr,
+                    ),
+                ));
+            }
+        }
+
         let result: Result<_> = match &self.input.data {
             syn::Data::Enum(e) => {
-                match self.find_meta(&self.input.attrs, "fmt").and_then(|m| {
-                    m.map(|m| self.parse_meta_fmt(&m, true)).transpose()
-                })? {
+                if let Some(rename) = self.find_rename(&self.input.attrs)? {
+                    return Err(Error::new(
+                        rename.span(),
+                        format!(
+                            "`#[{}(rename = \"...\")]` cannot be placed on an enum, \
+                             maybe you want to use `rename_all`?",
+                            self.trait_attr,
+                        ),
+                    ));
+                }
+                if let Some(alt) = self.find_alt(&self.input.attrs)? {
+                    return Err(Error::new(
+                        alt.lit.span(),
+                        format!(
+                            "`#[{}(alt = \"...\")]` cannot be placed on an enum, \
+                             place it on its variants instead",
+                            self.trait_attr,
+                        ),
+                    ));
+                }
+                let rename_all = self
+                    .find_rename_all(&self.input.attrs)?
+                    .map(|(case, _)| case);
+                let enum_delimiter = self.find_delimiter(&self.input.attrs)?;
+                let enum_discriminant = self.find_discriminant(&self.input.attrs)?;
+
+                let outer_fmt = self.find_fmt(&self.input.attrs)?;
+                match outer_fmt
+                    .map(|f| self.is_affix_fmt(&f).map(|affix| (f, affix)))
+                    .transpose()?
+                {
                     // #[display(fmt = "no placeholder")] on whole enum.
                     Some((fmt, false)) => {
                         e.variants.iter().try_for_each(|v| {
-                            if let Some(meta) = self.find_meta(&v.attrs, "fmt")? {
+                            if let Some(fmt) =
+                                self.find_fmt(&v.attrs)?.or(self.find_alt(&v.attrs)?)
+                            {
                                 Err(Error::new(
-                                    meta.span(),
+                                    fmt.span,
                                     "`fmt` cannot be used on variant when the whole enum has a format string without a placeholder, maybe you want to add a placeholder?",
                                 ))
                             } else {
@@ -500,27 +1450,91 @@ impl<'a, 'b> State<'a, 'b> {
                             }
                         })?;
 
+                        let arms = if self.refers_variant_name(&[])? {
+                            e.variants.iter().try_fold(
+                                TokenStream::new(),
+                                |arms, v| {
+                                    let name = self.get_name(
+                                        &v.attrs, &v.ident, &v.fields, rename_all,
+                                    )?;
+                                    let (fmt, _) = self.parse_fmt(
+                                        &fmt,
+                                        &syn::Fields::Unit,
+                                        &HashMap::default(),
+                                        Some(&name),
+                                    )?;
+                                    let v_name = &v.ident;
+                                    Ok::<_, Error>(quote_spanned! { self.input.span()=>
+                                        #arms Self::#v_name { .. } => #fmt,
+                                    })
+                                },
+                            )?
+                        } else {
+                            let (fmt, _) = self.parse_fmt(
+                                &fmt,
+                                &syn::Fields::Unit,
+                                &HashMap::default(),
+                                None,
+                            )?;
+                            quote_spanned! { self.input.span()=> _ => #fmt, }
+                        };
                         Ok(ParseResult {
-                            arms: quote_spanned! { self.input.span()=> _ => #fmt, },
+                            arms,
                             bounds: HashMap::default(),
                             requires_helper: false,
+                            predicates: Vec::new(),
                         })
                     }
                     // #[display(fmt = "one placeholder: {}")] on whole enum.
                     Some((outer_fmt, true)) => {
+                        let outer_lit = &outer_fmt.lit;
+                        let outer_placeholders =
+                            Placeholder::parse_named_ranges(&outer_lit.value())
+                                .into_iter()
+                                .map(|(name, range)| {
+                                    (name, lit_subspan(outer_lit, range))
+                                })
+                                .collect::<HashMap<_, _>>();
                         let fmt: Result<TokenStream> = e.variants.iter().try_fold(TokenStream::new(), |arms, v| {
                             let matcher = self.get_matcher(&v.fields);
-                            let fmt = if let Some(meta) = self.find_meta(&v.attrs, "fmt")? {
-                                self.parse_meta_fmt(&meta, false)?.0
+                            let field_attrs = self.parse_field_attributes(&v.fields)?;
+                            let name =
+                                self.get_name(&v.attrs, &v.ident, &v.fields, rename_all)?;
+                            let delimiter = self
+                                .find_delimiter_without_fmt(&v.attrs)?
+                                .or_else(|| enum_delimiter.clone());
+                            let fmt = if let Some(fmt) = self.find_fmt(&v.attrs)? {
+                                self.parse_fmt(&fmt, &v.fields, &field_attrs, Some(&name))?.0
+                            } else if let Some(fmt) =
+                                self.discriminant_fmt(e, v, enum_discriminant.as_ref())?
+                            {
+                                fmt
                             } else {
-                                self.infer_fmt(&v.fields, &v.ident)?
+                                self.infer_fmt(&v.fields, &field_attrs, &name, delimiter.as_ref())?
                             };
+                            let (fmt, _, _) = self.apply_alt(
+                                &v.attrs,
+                                &v.fields,
+                                &field_attrs,
+                                Some(&name),
+                                fmt,
+                            )?;
+                            let special_args = outer_placeholders
+                                .iter()
+                                .filter_map(|(ident, span)| {
+                                    let value = self
+                                        .special_placeholder(ident, *span, Some(&name))?;
+                                    let ident = format_ident!("{ident}", span = *span);
+                                    Some(value.map(|value| quote! { #ident = #value, }))
+                                })
+                                .collect::<Result<TokenStream>>()?;
                             let v_name = &v.ident;
                             Ok(quote_spanned! { fmt.span()=>
                                 #arms Self::#v_name #matcher => write!(
                                     _derive_more_display_formatter,
-                                    #outer_fmt,
-                                    _derive_more_DisplayAs(|_derive_more_display_formatter| #fmt)
+                                    #outer_lit,
+                                    _derive_more_DisplayAs(|_derive_more_display_formatter| #fmt),
+                                    #special_args
                                 ),
                             })
                         });
@@ -529,6 +1543,7 @@ impl<'a, 'b> State<'a, 'b> {
                             arms: quote_spanned! { self.input.span()=> #fmt },
                             bounds: HashMap::default(),
                             requires_helper: true,
+                            predicates: Vec::new(),
                         })
                     }
                     // No format attribute on whole enum.
@@ -538,24 +1553,66 @@ impl<'a, 'b> State<'a, 'b> {
                             let ParseResult {
                                 arms,
                                 mut bounds,
-                                requires_helper,
+                                mut requires_helper,
+                                predicates,
                             } = result;
                             let matcher = self.get_matcher(&v.fields);
                             let v_name = &v.ident;
+                            let field_attrs = self.parse_field_attributes(&v.fields)?;
+                            let name =
+                                self.get_name(&v.attrs, v_name, &v.fields, rename_all)?;
+                            let delimiter = self
+                                .find_delimiter_without_fmt(&v.attrs)?
+                                .or_else(|| enum_delimiter.clone());
                             let fmt: TokenStream;
                             let these_bounds: HashMap<_, _>;
 
-                            if let Some(meta) = self.find_meta(&v.attrs, "fmt")? {
-                                fmt = self.parse_meta_fmt(&meta, false)?.0;
-                                these_bounds =
-                                    self.get_used_type_params_bounds(&v.fields, &meta);
+                            if let Some(attr) = self.find_fmt(&v.attrs)? {
+                                let these_requires_helper;
+                                (fmt, these_requires_helper) = self.parse_fmt(
+                                    &attr,
+                                    &v.fields,
+                                    &field_attrs,
+                                    Some(&name),
+                                )?;
+                                requires_helper |= these_requires_helper;
+                                these_bounds = self.get_used_type_params_bounds(
+                                    &v.fields,
+                                    &field_attrs,
+                                    &attr,
+                                );
+                            } else if let Some(discriminant_fmt) =
+                                self.discriminant_fmt(e, v, enum_discriminant.as_ref())?
+                            {
+                                fmt = discriminant_fmt;
+                                these_bounds = HashMap::default();
                             } else {
-                                fmt = self.infer_fmt(&v.fields, v_name)?;
-                                these_bounds = self.infer_type_params_bounds(&v.fields);
+                                fmt = self.infer_fmt(
+                                    &v.fields,
+                                    &field_attrs,
+                                    &name,
+                                    delimiter.as_ref(),
+                                )?;
+                                these_bounds = self.infer_type_params_bounds(
+                                    &v.fields,
+                                    &field_attrs,
+                                    delimiter.as_ref(),
+                                );
                             };
-                            these_bounds.into_iter().for_each(|(ty, trait_names)| {
-                                bounds.entry(ty).or_default().extend(trait_names)
-                            });
+                            let (fmt, alt_requires_helper, alt_bounds) = self
+                                .apply_alt(
+                                    &v.attrs,
+                                    &v.fields,
+                                    &field_attrs,
+                                    Some(&name),
+                                    fmt,
+                                )?;
+                            requires_helper |= alt_requires_helper;
+                            these_bounds.into_iter().chain(alt_bounds).for_each(
+                                |(ty, trait_names)| {
+                                    bounds.entry(ty).or_default().extend(trait_names)
+                                },
+                            );
                             let arms = quote_spanned! { self.input.span()=>
                                 #arms Self::#v_name #matcher => #fmt,
                             };
@@ -564,6 +1621,7 @@ impl<'a, 'b> State<'a, 'b> {
                                 arms,
                                 bounds,
                                 requires_helper,
+                                predicates,
                             })
                         },
                     ),
@@ -572,189 +1630,626 @@ impl<'a, 'b> State<'a, 'b> {
             syn::Data::Struct(s) => {
                 let matcher = self.get_matcher(&s.fields);
                 let name = &self.input.ident;
+                let field_attrs = self.parse_field_attributes(&s.fields)?;
+                let display_name =
+                    self.get_name(&self.input.attrs, name, &s.fields, None)?;
+                let delimiter = self.find_delimiter_without_fmt(&self.input.attrs)?;
                 let fmt: TokenStream;
-                let bounds: HashMap<_, _>;
-
-                if let Some(meta) = self.find_meta(&self.input.attrs, "fmt")? {
-                    fmt = self.parse_meta_fmt(&meta, false)?.0;
-                    bounds = self.get_used_type_params_bounds(&s.fields, &meta);
+                let mut bounds: HashMap<_, _>;
+                let mut requires_helper = false;
+
+                if let Some(attr) = self.find_fmt(&self.input.attrs)? {
+                    (fmt, requires_helper) =
+                        self.parse_fmt(&attr, &s.fields, &field_attrs, None)?;
+                    bounds = self.get_used_type_params_bounds(
+                        &s.fields,
+                        &field_attrs,
+                        &attr,
+                    );
                 } else {
-                    fmt = self.infer_fmt(&s.fields, name)?;
-                    bounds = self.infer_type_params_bounds(&s.fields);
+                    fmt = self.infer_fmt(
+                        &s.fields,
+                        &field_attrs,
+                        &display_name,
+                        delimiter.as_ref(),
+                    )?;
+                    bounds = self.infer_type_params_bounds(
+                        &s.fields,
+                        &field_attrs,
+                        delimiter.as_ref(),
+                    );
                 }
+                let (fmt, alt_requires_helper, alt_bounds) = self.apply_alt(
+                    &self.input.attrs,
+                    &s.fields,
+                    &field_attrs,
+                    None,
+                    fmt,
+                )?;
+                requires_helper |= alt_requires_helper;
+                alt_bounds.into_iter().for_each(|(ty, trait_names)| {
+                    bounds.entry(ty).or_default().extend(trait_names)
+                });
 
                 Ok(ParseResult {
                     arms: quote_spanned! { self.input.span()=> #name #matcher => #fmt, },
                     bounds,
-                    requires_helper: false,
+                    requires_helper,
+                    predicates: Vec::new(),
                 })
             }
             syn::Data::Union(_) => {
-                let meta =
-                    self.find_meta(&self.input.attrs, "fmt")?.ok_or_else(|| {
-                        Error::new(
-                            self.input.span(),
-                            "Cannot automatically infer format for unions",
-                        )
-                    })?;
-                let fmt = self.parse_meta_fmt(&meta, false)?.0;
+                let attr = self.find_fmt(&self.input.attrs)?.ok_or_else(|| {
+                    Error::new(
+                        self.input.span(),
+                        "Cannot automatically infer format for unions",
+                    )
+                })?;
+                let (fmt, requires_helper) = self.parse_fmt(
+                    &attr,
+                    &syn::Fields::Unit,
+                    &HashMap::default(),
+                    None,
+                )?;
+                let (fmt, alt_requires_helper, _) = self.apply_alt(
+                    &self.input.attrs,
+                    &syn::Fields::Unit,
+                    &HashMap::default(),
+                    None,
+                    fmt,
+                )?;
 
                 Ok(ParseResult {
                     arms: quote_spanned! { self.input.span()=> _ => #fmt, },
                     bounds: HashMap::default(),
-                    requires_helper: false,
+                    requires_helper: requires_helper || alt_requires_helper,
+                    predicates: Vec::new(),
                 })
             }
         };
 
         let mut result = result?;
 
-        let Some(meta) = self.find_meta(&self.input.attrs, "bound")? else {
+        let Some(extra_bounds) = self.find_bound(&self.input.attrs)? else {
             return Ok(result);
         };
 
-        let span = meta.span();
-
-        let syn::Meta::List(syn::MetaList { nested: meta, .. }) = meta else {
-            return Err(Error::new(span, self.get_proper_bound_syntax()));
-        };
-
-        if meta.len() != 1 {
-            return Err(Error::new(span, self.get_proper_bound_syntax()));
+        match extra_bounds {
+            BoundAttribute::Predicates(predicates) => {
+                result.predicates.extend(predicates);
+            }
+            BoundAttribute::Str(lit) => {
+                let extra_bounds = self.parse_meta_bounds(&lit)?;
+                extra_bounds.into_iter().for_each(|(ty, trait_names)| {
+                    result.bounds.entry(ty).or_default().extend(trait_names)
+                });
+            }
         }
 
-        let syn::NestedMeta::Meta(syn::Meta::NameValue(meta)) = &meta[0] else {
-            return Err(Error::new(span, self.get_proper_bound_syntax()));
-        };
-
-        let syn::Lit::Str(extra_bounds) = &meta.lit else {
-            return Err(Error::new(span, self.get_proper_bound_syntax()));
-        };
-
-        let extra_bounds = self.parse_meta_bounds(extra_bounds)?;
-
-        extra_bounds.into_iter().for_each(|(ty, trait_names)| {
-            result.bounds.entry(ty).or_default().extend(trait_names)
-        });
-
         Ok(result)
     }
     fn get_used_type_params_bounds(
         &self,
         fields: &syn::Fields,
-        meta: &syn::Meta,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        fmt: &FmtAttribute,
     ) -> HashMap<syn::Type, HashSet<syn::TraitBound>> {
         if self.type_params.is_empty() {
             return HashMap::default();
         }
 
-        let fields_type_params: HashMap<syn::Path, _> = fields
+        let fields_types: HashMap<syn::Path, _> = fields
             .iter()
             .enumerate()
-            .filter_map(|(i, field)| {
-                utils::get_if_type_parameter_used_in_type(&self.type_params, &field.ty)
-                    .map(|ty| {
-                        (
-                            field
-                                .ident
-                                .clone()
-                                .unwrap_or_else(|| format_ident!("_{i}"))
-                                .into(),
-                            ty,
-                        )
-                    })
+            .map(|(i, field)| {
+                let binding =
+                    field.ident.clone().unwrap_or_else(|| format_ident!("_{i}"));
+                (binding.into(), &field.ty)
             })
             .collect();
-        if fields_type_params.is_empty() {
-            return HashMap::default();
-        }
 
-        let syn::Meta::List(list) = meta else {
-            // This one has been checked already in `get_meta_fmt()` method.
-            unreachable!()
-        };
-        let fmt_args: HashMap<_, _> = list
-            .nested
+        let fmt_span = fmt.lit.span();
+        let named_args: HashMap<_, _> = fmt
+            .args
             .iter()
-            .skip(1) // skip fmt = "..."
-            .enumerate()
-            .filter_map(|(i, arg)| match arg {
-                syn::NestedMeta::Lit(syn::Lit::Str(ref s)) => {
-                    syn::parse_str(&s.value()).ok().map(|id| (i, id))
-                }
-                syn::NestedMeta::Meta(syn::Meta::Path(ref id)) => Some((i, id.clone())),
-                // This one has been checked already in `get_meta_fmt()` method.
-                _ => unreachable!(),
-            })
+            .filter_map(|arg| Some((arg.name.as_ref()?.to_string(), arg)))
             .collect();
-        let (fmt_string, fmt_span) = match &list.nested[0] {
-            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
-                path,
-                lit: syn::Lit::Str(s),
-                ..
-            })) if path
-                .segments
-                .first()
-                .expect("path shouldn't be empty")
-                .ident
-                == "fmt" =>
-            {
-                (s.value(), s.span())
-            }
-            // This one has been checked already in get_meta_fmt() method.
-            _ => unreachable!(),
-        };
 
-        Placeholder::parse_fmt_string(&fmt_string).into_iter().fold(
-            HashMap::default(),
-            |mut bounds, pl| {
-                let arg = match pl.arg {
-                    Parameter::Positional(i) => fmt_args.get(&i).cloned(),
-                    Parameter::Named(i) => {
-                        Some(format_ident!("{i}", span = fmt_span).into())
+        Placeholder::parse_fmt_string(&fmt.lit.value())
+            .into_iter()
+            .fold(HashMap::default(), |mut bounds, pl| {
+                let (arg, field_attr) = match pl.arg {
+                    Parameter::Positional(i) => {
+                        (fmt.args.get(i).and_then(|arg| arg.alias.clone()), None)
                     }
+                    Parameter::Named(i) => match named_args.get(&i) {
+                        Some(arg) => (arg.alias.clone(), None),
+                        // Captured field with a custom format is formatted
+                        // according to it only.
+                        None => (
+                            Some(format_ident!("{i}", span = fmt_span).into()),
+                            field_attrs.get(&i),
+                        ),
+                    },
+                };
+                let Some(ty) = arg.as_ref().and_then(|arg| fields_types.get(arg))
+                else {
+                    return bounds;
                 };
-                if let Some(arg) = &arg {
-                    if fields_type_params.contains_key(arg) {
-                        bounds
-                            .entry(fields_type_params[arg].clone())
-                            .or_insert_with(HashSet::default)
-                            .insert(trait_name_to_trait_bound(pl.trait_name));
+                let trait_names = field_attr.map_or_else(
+                    || vec![pl.trait_name],
+                    |attr| attr.trait_names(pl.trait_name),
+                );
+                let ty = field_attr.map_or(Some(*ty), |attr| attr.bounded_type(ty));
+                if let Some(ty) = ty.and_then(|ty| {
+                    utils::get_if_type_parameter_used_in_type(&self.type_params, ty)
+                }) {
+                    if !trait_names.is_empty() {
+                        bounds.entry(ty).or_insert_with(HashSet::default).extend(
+                            trait_names.into_iter().map(trait_name_to_trait_bound),
+                        );
                     }
                 }
                 bounds
-            },
-        )
+            })
     }
     fn infer_type_params_bounds(
         &self,
         fields: &syn::Fields,
+        field_attrs: &HashMap<String, FieldAttribute>,
+        delimiter: Option<&syn::LitStr>,
     ) -> HashMap<syn::Type, HashSet<syn::TraitBound>> {
         if self.type_params.is_empty() {
             return HashMap::default();
         }
-        if let syn::Fields::Unit = fields {
-            return HashMap::default();
+        // infer_fmt() uses only the inferred fields, and errors if there are none.
+        self.find_inferred_fields(fields, field_attrs, delimiter)
+            .unwrap_or_default()
+            .into_iter()
+            .fold(HashMap::default(), |mut bounds, (binding, field)| {
+                let trait_name = attribute_name_to_trait_name(self.trait_attr);
+                let field_attr = field_attrs.get(&binding.to_string());
+                let trait_names = field_attr.map_or_else(
+                    || vec![trait_name],
+                    |attr| attr.trait_names(trait_name),
+                );
+                let ty = field_attr
+                    .map_or(Some(&field.ty), |attr| attr.bounded_type(&field.ty));
+                if let Some(ty) = ty.and_then(|ty| {
+                    utils::get_if_type_parameter_used_in_type(&self.type_params, ty)
+                }) {
+                    if !trait_names.is_empty() {
+                        bounds.entry(ty).or_insert_with(HashSet::default).extend(
+                            trait_names.into_iter().map(trait_name_to_trait_bound),
+                        );
+                    }
+                }
+                bounds
+            })
+    }
+}
+
+/// Parsed `#[display(...)]` attribute (or the one of any other formatting
+/// trait).
+enum Attribute {
+    /// `#[display(fmt = "...", args...)]` or `#[display("...", args...)]`.
+    Fmt(FmtAttribute),
+
+    /// `#[display(alt = "...", args...)]`, used for the alternate `{:#}` form.
+    Alt(FmtAttribute),
+
+    /// `#[display(bound(...))]` or `#[display(bound = "...")]`.
+    Bound(BoundAttribute),
+
+    /// `#[display(rename_all = "...")]`, along with the parsed rule.
+    RenameAll(syn::LitStr, Case),
+
+    /// `#[display(rename = "...")]`.
+    Rename(syn::LitStr),
+
+    /// `#[display(delimiter = "...")]`.
+    Delimiter(syn::LitStr),
+
+    /// `#[display(pad)]`.
+    Pad(Ident),
+
+    /// `#[display(discriminant)]`.
+    Discriminant(Ident),
+}
+
+/// Finds the primitive integer type the enum is represented with in its
+/// `#[repr(...)]` attribute, defaulting to `isize`.
+fn find_repr(attrs: &[syn::Attribute]) -> Result<Ident> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let metas = attr.parse_args_with(
+            Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        )?;
+        let ty = metas.into_iter().find_map(|meta| match meta {
+            syn::Meta::Path(path) => path
+                .get_ident()
+                .filter(|ident| REPR_INTEGER_TYPES.iter().any(|ty| *ident == ty))
+                .cloned(),
+            _ => None,
+        });
+        if let Some(ty) = ty {
+            return Ok(ty);
         }
-        // infer_fmt() uses only first field.
-        fields
+    }
+    Ok(format_ident!("isize"))
+}
+
+/// Checks whether the given [`Parameter`] is a special `{_variant}` or `{_type}`
+/// placeholder.
+fn is_special_placeholder(arg: &Parameter) -> bool {
+    matches!(
+        arg,
+        Parameter::Named(name) if name == VARIANT_PLACEHOLDER || name == TYPE_PLACEHOLDER,
+    )
+}
+
+/// Checks whether the given type (or the one it references) is one of the
+/// [`PATH_TYPES`].
+fn is_path_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(ty) => is_path_type(&ty.elem),
+        syn::Type::Paren(ty) => is_path_type(&ty.elem),
+        syn::Type::Group(ty) => is_path_type(&ty.elem),
+        ty => PATH_TYPES
             .iter()
-            .take(1)
-            .filter_map(|field| {
-                utils::get_if_type_parameter_used_in_type(&self.type_params, &field.ty)
-                    .map(|ty| {
-                        (
-                            ty,
-                            [trait_name_to_trait_bound(attribute_name_to_trait_name(
-                                self.trait_attr,
-                            ))]
-                            .iter()
-                            .cloned()
-                            .collect(),
+            .any(|tail| utils::is_type_path_ends_with_segment(ty, tail)),
+    }
+}
+
+/// Returns an expression displaying the `AsRef<Path>` value bound to
+/// `binding`.
+fn path_display(binding: &Ident, span: Span) -> TokenStream {
+    quote_spanned! { span=>
+        ::std::convert::AsRef::<::std::path::Path>::as_ref(#binding).display()
+    }
+}
+
+/// Custom trait bounds of `#[display(bound...)]` attribute.
+enum BoundAttribute {
+    /// `#[display(bound(T: Trait1 + Trait2, U: Trait3))]`, with arbitrary
+    /// `where` clause predicates.
+    Predicates(Punctuated<syn::WherePredicate, syn::Token![,]>),
+
+    /// Legacy `#[display(bound = "T, U: Trait1 + Trait2, V: Trait3")]`, with
+    /// trait bounds of type parameters only.
+    Str(syn::LitStr),
+}
+
+impl BoundAttribute {
+    /// Returns the [`Span`] of these bounds.
+    fn span(&self) -> Span {
+        match self {
+            Self::Predicates(predicates) => predicates.span(),
+            Self::Str(lit) => lit.span(),
+        }
+    }
+}
+
+/// Field-level `#[display(...)]` attribute, specifying how the field is
+/// formatted whenever it's captured as `{field}` or its format is inferred.
+enum FieldAttribute {
+    /// `#[display(fmt = "...")]` or `#[display("...")]`, with the field being
+    /// the only positional argument.
+    Fmt(syn::LitStr),
+
+    /// `#[display(with = path::to::fn)]`, where the function has a
+    /// `fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result` signature.
+    With(syn::Path),
+
+    /// `#[display(forward)]`, making the whole value formatted exactly as
+    /// this field, including width, precision and flags.
+    Forward(Ident),
+
+    /// `#[display(join = "...")]`, formatting the field's items separated by
+    /// the given string.
+    Join(syn::LitStr),
+
+    /// `#[display(some = "...", none = "...")]`, formatting the value of an
+    /// [`Option`] field with the `some` format string (having the value as its
+    /// only positional argument), or the `none` text if there is no value.
+    Option {
+        /// [`Span`] of the first argument of this attribute.
+        span: Span,
+
+        /// Format string of the value, if any.
+        some: Option<syn::LitStr>,
+
+        /// Text to be written instead of a missing value, if any.
+        none: Option<syn::LitStr>,
+    },
+
+    /// `#[lower_hex(bytes)]` or `#[upper_hex(bytes)]`, hex-encoding the bytes
+    /// of an `AsRef<[u8]>` field one by one, with the `0x` prefix for the
+    /// alternate flag and zeros padding the output up to the width.
+    Bytes {
+        /// [`Span`] of this attribute's argument.
+        span: Span,
+
+        /// Whether upper case hex digits are used.
+        upper: bool,
+    },
+
+    /// `#[display(path)]`, formatting an `AsRef<Path>` field via
+    /// `Path::display()`, along with its [`Span`].
+    ///
+    /// Fields of [`PATH_TYPES`] are formatted this way without the attribute.
+    Path(Span),
+}
+
+impl FieldAttribute {
+    /// Returns an expression formatting the field of the given type, bound to
+    /// `binding`, into the `_derive_more_display_formatter`.
+    fn fmt_expr(
+        &self,
+        binding: &Ident,
+        ty: &syn::Type,
+        trait_path: &TokenStream,
+    ) -> TokenStream {
+        match self {
+            Self::Fmt(lit) => quote_spanned! { lit.span()=>
+                write!(_derive_more_display_formatter, #lit, #binding)
+            },
+            Self::With(path) => quote_spanned! { path.span()=>
+                #path(#binding, _derive_more_display_formatter)
+            },
+            Self::Forward(forward) => quote_spanned! { forward.span()=>
+                #trait_path::fmt(#binding, _derive_more_display_formatter)
+            },
+            Self::Join(separator) => {
+                // Dereference the field's own references, so the items are
+                // iterated through a single one.
+                let mut derefs = TokenStream::new();
+                let mut ty = ty;
+                while let syn::Type::Reference(r) = ty {
+                    derefs.extend(quote! { * });
+                    ty = &r.elem;
+                }
+                quote_spanned! { separator.span()=>
+                    {
+                        let mut _derive_more_display_items =
+                            ::core::iter::IntoIterator::into_iter(#derefs #binding);
+                        if let ::core::option::Option::Some(_derive_more_display_item) =
+                            _derive_more_display_items.next()
+                        {
+                            #trait_path::fmt(
+                                &_derive_more_display_item,
+                                _derive_more_display_formatter,
+                            )?;
+                        }
+                        for _derive_more_display_item in _derive_more_display_items {
+                            _derive_more_display_formatter.write_str(#separator)?;
+                            #trait_path::fmt(
+                                &_derive_more_display_item,
+                                _derive_more_display_formatter,
+                            )?;
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                }
+            }
+            Self::Option { span, some, none } => {
+                let some = match some {
+                    Some(lit) => quote! {
+                        write!(
+                            _derive_more_display_formatter,
+                            #lit,
+                            _derive_more_display_value,
                         )
-                    })
-            })
-            .collect()
+                    },
+                    None => quote! {
+                        #trait_path::fmt(
+                            _derive_more_display_value,
+                            _derive_more_display_formatter,
+                        )
+                    },
+                };
+                let none = none.as_ref().map_or_else(
+                    || quote! { ::core::result::Result::Ok(()) },
+                    |lit| quote! { _derive_more_display_formatter.write_str(#lit) },
+                );
+                quote_spanned! { *span=>
+                    match #binding {
+                        ::core::option::Option::Some(_derive_more_display_value) => {
+                            #some
+                        }
+                        ::core::option::Option::None => #none,
+                    }
+                }
+            }
+            Self::Bytes { span, upper } => {
+                let digits = if *upper { "{:02X}" } else { "{:02x}" };
+                quote_spanned! { *span=>
+                    {
+                        let _derive_more_display_bytes: &[u8] =
+                            ::core::convert::AsRef::<[u8]>::as_ref(#binding);
+                        let mut _derive_more_display_len =
+                            _derive_more_display_bytes.len() * 2;
+                        if _derive_more_display_formatter.alternate() {
+                            _derive_more_display_formatter.write_str("0x")?;
+                            _derive_more_display_len += 2;
+                        }
+                        let _derive_more_display_width =
+                            _derive_more_display_formatter.width().unwrap_or(0);
+                        for _ in _derive_more_display_len.._derive_more_display_width {
+                            _derive_more_display_formatter.write_str("0")?;
+                        }
+                        for _derive_more_display_byte in _derive_more_display_bytes {
+                            write!(
+                                _derive_more_display_formatter,
+                                #digits,
+                                _derive_more_display_byte,
+                            )?;
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                }
+            }
+            Self::Path(span) => {
+                let path = path_display(binding, *span);
+                quote_spanned! { *span=>
+                    #trait_path::fmt(&#path, _derive_more_display_formatter)
+                }
+            }
+        }
+    }
+
+    /// Returns the type the formatting traits are required on for the field of
+    /// the given type, if any.
+    ///
+    /// For `#[display(join = "...")]` this is the item type of a slice, an
+    /// array or a collection (its first type argument), and for
+    /// `#[display(some = "...", none = "...")]` it's the type of the
+    /// [`Option`]'s value.
+    fn bounded_type<'t>(&self, ty: &'t syn::Type) -> Option<&'t syn::Type> {
+        fn item_type(ty: &syn::Type) -> Option<&syn::Type> {
+            match ty {
+                syn::Type::Reference(ty) => item_type(&ty.elem),
+                syn::Type::Paren(ty) => item_type(&ty.elem),
+                syn::Type::Group(ty) => item_type(&ty.elem),
+                syn::Type::Slice(ty) => Some(&ty.elem),
+                syn::Type::Array(ty) => Some(&ty.elem),
+                syn::Type::Path(ty) => match &ty.path.segments.last()?.arguments {
+                    syn::PathArguments::AngleBracketed(args) => {
+                        args.args.iter().find_map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        match self {
+            Self::Join(_) | Self::Option { .. } => item_type(ty),
+            Self::Fmt(_) | Self::With(_) | Self::Forward(_) => Some(ty),
+            Self::Bytes { .. } | Self::Path(_) => None,
+        }
+    }
+
+    /// Returns [`Span`] of this attribute's argument.
+    fn span(&self) -> Span {
+        match self {
+            Self::Fmt(lit) => lit.span(),
+            Self::With(path) => path.span(),
+            Self::Forward(forward) => forward.span(),
+            Self::Join(separator) => separator.span(),
+            Self::Option { span, .. } | Self::Bytes { span, .. } | Self::Path(span) => {
+                *span
+            }
+        }
+    }
+
+    /// Returns names of the formatting traits the field is required to
+    /// implement, given it's formatted as `trait_name` by default.
+    fn trait_names(&self, trait_name: &'static str) -> Vec<&'static str> {
+        match self {
+            Self::Fmt(lit)
+            | Self::Option {
+                some: Some(lit), ..
+            } => Placeholder::parse_fmt_string(&lit.value())
+                .into_iter()
+                .filter(|p| p.arg == Parameter::Positional(0))
+                .map(|p| p.trait_name)
+                .collect(),
+            Self::With(_) | Self::Bytes { .. } | Self::Path(_) => Vec::new(),
+            Self::Forward(_) | Self::Join(_) | Self::Option { some: None, .. } => {
+                vec![trait_name]
+            }
+        }
+    }
+}
+
+/// Format string along with its arguments, to be passed to `write!`.
+struct FmtAttribute {
+    /// Format string literal.
+    lit: syn::LitStr,
+
+    /// Arguments following the format string.
+    args: Vec<FmtArgument>,
+
+    /// [`Span`] of the whole attribute.
+    span: Span,
+}
+
+/// Single argument of a [`FmtAttribute`].
+struct FmtArgument {
+    /// Name of this argument, if it's specified as `name = expr`.
+    name: Option<Ident>,
+
+    /// Expression passed to `write!` as this argument.
+    expr: TokenStream,
+
+    /// Field (or any other binding) this argument refers to as a whole, if
+    /// any. Used for trait bounds inference.
+    alias: Option<syn::Path>,
+}
+
+impl FmtArgument {
+    /// Creates a [`FmtArgument`] out of a real Rust expression, as used in
+    /// `#[display("...", args...)]`.
+    fn from_expr(expr: syn::Expr) -> Self {
+        if let syn::Expr::Assign(syn::ExprAssign { left, right, .. }) = &expr {
+            if let syn::Expr::Path(syn::ExprPath {
+                path, qself: None, ..
+            }) = &**left
+            {
+                if let Some(name) = path.get_ident() {
+                    return Self {
+                        name: Some(name.clone()),
+                        alias: Self::alias_of(right),
+                        expr: right.to_token_stream(),
+                    };
+                }
+            }
+        }
+        Self {
+            name: None,
+            alias: Self::alias_of(&expr),
+            expr: expr.into_token_stream(),
+        }
+    }
+
+    /// Creates a [`FmtArgument`] out of a string literal, parsed as an
+    /// expression, as used in `#[display(fmt = "...", "args"...)]`.
+    fn from_lit_str(lit: &syn::LitStr) -> Result<Self> {
+        Ok(Self {
+            name: None,
+            alias: syn::parse_str(&lit.value()).ok(),
+            expr: lit.parse().map_err(|e| Error::new(lit.span(), e))?,
+        })
+    }
+
+    /// Creates a [`FmtArgument`] out of a plain path, as used in
+    /// `#[display(fmt = "...", args...)]`.
+    fn from_path(path: syn::Path) -> Self {
+        Self {
+            name: None,
+            expr: path.to_token_stream(),
+            alias: Some(path),
+        }
+    }
+
+    /// Returns the binding the given expression refers to, if it's either a
+    /// plain identifier, or a `self.field` access.
+    fn alias_of(expr: &syn::Expr) -> Option<syn::Path> {
+        match expr {
+            syn::Expr::Path(p) if p.qself.is_none() => {
+                p.path.get_ident().map(|i| i.clone().into())
+            }
+            syn::Expr::Field(syn::ExprField { base, member, .. }) if matches!(&**base, syn::Expr::Path(p) if p.path.is_ident("self")) => {
+                Some(match member {
+                    syn::Member::Named(i) => i.clone().into(),
+                    syn::Member::Unnamed(i) => format_ident!("_{}", i.index).into(),
+                })
+            }
+            _ => None,
+        }
     }
 }
 
@@ -839,6 +2334,55 @@ impl Placeholder {
             })
             .collect()
     }
+
+    /// Parses names of all the named arguments (including width and precision
+    /// ones) from a given formatting string along with their byte ranges in it.
+    fn parse_named_ranges(s: &str) -> Vec<(String, Range<usize>)> {
+        let range = |ident: &str| {
+            // Parsed identifiers are always sub-slices of the parsed string.
+            let start = ident.as_ptr() as usize - s.as_ptr() as usize;
+            (ident.to_owned(), start..start + ident.len())
+        };
+        parsing::format_string(s)
+            .into_iter()
+            .flat_map(|f| f.formats)
+            .flat_map(|format| {
+                let width = format.spec.and_then(|s| match s.width {
+                    Some(parsing::Count::Parameter(arg)) => Some(arg),
+                    _ => None,
+                });
+                let precision = format.spec.and_then(|s| match s.precision {
+                    Some(parsing::Precision::Count(parsing::Count::Parameter(arg))) => {
+                        Some(arg)
+                    }
+                    _ => None,
+                });
+                format.arg.into_iter().chain(width).chain(precision)
+            })
+            .filter_map(|arg| match arg {
+                parsing::Argument::Identifier(ident) => Some(range(ident)),
+                parsing::Argument::Integer(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Returns the [`Span`] of the given byte `range` of the `lit`eral's value, if the
+/// compiler allows it, or the [`Span`] of the whole `lit`eral otherwise.
+fn lit_subspan(lit: &syn::LitStr, range: Range<usize>) -> Span {
+    let token = lit.token();
+    let repr = token.to_string();
+    let value = lit.value();
+
+    // Only literals without escape sequences have their value verbatim in the
+    // source code, so the `range` can be shifted by the opening quote.
+    let offset = repr.find('"').map(|quote| quote + 1).filter(|&start| {
+        repr.get(start..start + value.len()) == Some(value.as_str())
+            && repr[start + value.len()..].starts_with('"')
+    });
+    offset
+        .and_then(|offset| token.subspan(range.start + offset..range.end + offset))
+        .unwrap_or_else(|| lit.span())
 }
 
 #[cfg(test)]
@@ -891,3 +2435,21 @@ mod placeholder_parse_fmt_string_spec {
         );
     }
 }
+
+#[cfg(test)]
+mod placeholder_parse_named_ranges_spec {
+    use super::*;
+
+    #[test]
+    fn indicates_byte_range_of_each_named_argument() {
+        assert_eq!(
+            Placeholder::parse_named_ranges("{{a}} {b:>w$.p$} {0} {ж:?}"),
+            vec![
+                ("b".to_owned(), 7..8),
+                ("w".to_owned(), 10..11),
+                ("p".to_owned(), 13..14),
+                ("ж".to_owned(), 22..24),
+            ],
+        );
+    }
+}
@@ -1,16 +1,27 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{spanned::Spanned as _, Error, Result};
-
-use crate::utils::{
-    self, AttrParams, DeriveType, FullMetaInfo, HashSet, MetaInfo, MultiFieldData,
-    State,
+use quote::{format_ident, quote, ToTokens as _};
+use syn::{parse::ParseStream, spanned::Spanned as _, Error, Result};
+
+use crate::{
+    display, from,
+    utils::{
+        self, AttrParams, DeriveType, FullMetaInfo, HashSet, MetaInfo, MultiFieldData,
+        State,
+    },
 };
 
 pub fn expand(
     input: &syn::DeriveInput,
     trait_name: &'static str,
 ) -> Result<TokenStream> {
+    let krate = std_or_core();
+
+    let (input, display_input) = split_display_attrs(input)?;
+    let display = display_input
+        .map(|input| display::expand(&input, "Display"))
+        .transpose()?;
+    let input = &input;
+
     let syn::DeriveInput {
         ident, generics, ..
     } = input;
@@ -18,7 +29,7 @@ pub fn expand(
     let state = State::with_attr_params(
         input,
         trait_name,
-        quote! { ::std::error },
+        quote! { #krate::error },
         trait_name.to_lowercase(),
         allowed_attr_params(),
     )?;
@@ -32,14 +43,14 @@ pub fn expand(
         })
         .collect();
 
-    let (bounds, source, provide) = match state.derive_type {
+    let (bounds, source, provide, from) = match state.derive_type {
         DeriveType::Named | DeriveType::Unnamed => render_struct(&type_params, &state)?,
         DeriveType::Enum => render_enum(&type_params, &state)?,
     };
 
     let source = source.map(|source| {
         quote! {
-            fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+            fn source(&self) -> Option<&(dyn #krate::error::Error + 'static)> {
                 #source
             }
         }
@@ -47,9 +58,9 @@ pub fn expand(
 
     let provide = provide.map(|provide| {
         quote! {
-            fn provide<'_demand>(&'_demand self, demand: &mut ::std::any::Demand<'_demand>) {
-                 #provide
-             }
+            fn provide<'_request>(&'_request self, request: &mut #krate::error::Request<'_request>) {
+                #provide
+            }
         }
     });
 
@@ -61,18 +72,17 @@ pub fn expand(
             &generics,
             quote! {
                 where
-                    #ident<#(#generic_parameters),*>: ::std::fmt::Debug + ::std::fmt::Display
+                    #ident<#(#generic_parameters),*>: ::core::fmt::Debug + ::core::fmt::Display
             },
         );
     }
 
     if !bounds.is_empty() {
-        let bounds = bounds.iter();
         generics = utils::add_extra_where_clauses(
             &generics,
             quote! {
                 where
-                    #(#bounds: ::std::fmt::Debug + ::std::fmt::Display + ::std::error::Error + 'static),*
+                    #(#bounds),*
             },
         );
     }
@@ -80,37 +90,211 @@ pub fn expand(
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let render = quote! {
+        #display
+
         #[automatically_derived]
-        impl #impl_generics ::std::error::Error for #ident #ty_generics #where_clause {
+        impl #impl_generics #krate::error::Error for #ident #ty_generics #where_clause {
             #source
             #provide
         }
+
+        #(#from)*
     };
 
     Ok(render)
 }
 
-fn render_struct(
-    type_params: &HashSet<syn::Ident>,
-    state: &State,
-) -> Result<(HashSet<syn::Type>, Option<TokenStream>, Option<TokenStream>)> {
-    let parsed_fields = parse_fields(type_params, state)?;
+/// Checks whether the given attribute is an `#[<name>("...", args...)]` one,
+/// specifying the `Display` format of the error.
+fn is_fmt_attr(attr: &syn::Attribute, name: &str) -> bool {
+    attr.path.is_ident(name)
+        && attr
+            .parse_args_with(|input: ParseStream| {
+                let is_fmt = input.peek(syn::LitStr);
+                input.parse::<TokenStream>().map(|_| is_fmt)
+            })
+            .unwrap_or_default()
+}
+
+/// Checks whether the given attribute is an `#[error("...", args...)]` one.
+fn is_display_attr(attr: &syn::Attribute) -> bool {
+    is_fmt_attr(attr, "error")
+}
+
+/// Checks whether the given attribute is the `#[error(transparent)]` one.
+fn is_transparent_attr(attr: &syn::Attribute) -> bool {
+    attr.path.is_ident("error")
+        && attr
+            .parse_args::<syn::Ident>()
+            .map_or(false, |ident| ident == "transparent")
+}
+
+/// Splits `#[error("...", args...)]` attributes out of the given type and its
+/// variants, returning the type without them, along with the one to derive
+/// `Display` for, if any of these attributes (or `#[error(transparent)]` one)
+/// is present.
+///
+/// In the latter, these attributes become `#[display("...", args...)]` ones,
+/// while all the other `#[error(...)]` attributes are removed. The other
+/// `#[display(...)]` attributes (like `bound` or `rename_all`) are kept as is,
+/// while specifying a format string in both `#[error(...)]` and `#[display(...)]`
+/// attributes is an error. The format of transparent structs and variants is
+/// always inferred from their only field.
+fn split_display_attrs(
+    input: &syn::DeriveInput,
+) -> Result<(syn::DeriveInput, Option<syn::DeriveInput>)> {
+    fn split(
+        attrs: &mut Vec<syn::Attribute>,
+        display_attrs: &mut Vec<syn::Attribute>,
+        fields: Option<&syn::Fields>,
+    ) -> Result<bool> {
+        let transparent = attrs.iter().any(is_transparent_attr);
+        if let Some(fmt) = attrs.iter().find(|attr| is_display_attr(attr)) {
+            if transparent {
+                return Err(Error::new(
+                    fmt.span(),
+                    "`#[error(transparent)]` cannot be used along with a format string",
+                ));
+            }
+        }
+        let (fmts, others): (Vec<_>, Vec<_>) =
+            attrs.drain(..).partition(is_display_attr);
+        *attrs = others;
+        let has_display = transparent || !fmts.is_empty();
+        if has_display {
+            if let Some(fmt) = display_attrs.iter().find(|a| is_fmt_attr(a, "display"))
+            {
+                return Err(Error::new(
+                    fmt.path.span(),
+                    format!(
+                        "`#[display(\"...\")]` cannot be used along with `#[error({})]`",
+                        if transparent { "transparent" } else { "\"...\"" },
+                    ),
+                ));
+            }
+        }
+        display_attrs.retain(|attr| !attr.path.is_ident("error"));
+        for mut attr in fmts {
+            attr.path = syn::parse_quote! { display };
+            if let Some(syn::Fields::Unnamed(fields)) = fields {
+                map_positional_placeholders(&mut attr, fields.unnamed.len())?;
+            }
+            display_attrs.push(attr);
+        }
+        Ok(has_display)
+    }
+    fn strip_fields(fields: &mut syn::Fields) {
+        for field in fields.iter_mut() {
+            field.attrs.retain(|attr| !attr.path.is_ident("error"));
+        }
+    }
+
+    let mut input = input.clone();
+    let mut display_input = input.clone();
+    let fields = match &input.data {
+        syn::Data::Struct(data) => Some(data.fields.clone()),
+        _ => None,
+    };
+    let mut has_display =
+        split(&mut input.attrs, &mut display_input.attrs, fields.as_ref())?;
+    match (&mut input.data, &mut display_input.data) {
+        (syn::Data::Enum(data), syn::Data::Enum(display_data)) => {
+            for (v, display_v) in
+                data.variants.iter_mut().zip(&mut display_data.variants)
+            {
+                has_display |=
+                    split(&mut v.attrs, &mut display_v.attrs, Some(&v.fields))?;
+                strip_fields(&mut display_v.fields);
+            }
+        }
+        (syn::Data::Struct(_), syn::Data::Struct(display_data)) => {
+            strip_fields(&mut display_data.fields);
+        }
+        _ => {}
+    }
+
+    Ok((input, has_display.then_some(display_input)))
+}
+
+/// Maps positional placeholders (like `{0}` or `{1:?}`) of the given
+/// `#[display("...")]` attribute onto the `_0`, `_1`, etc. names of the first
+/// `len` tuple fields, if the format string has no arguments to refer to
+/// otherwise, the same way [`thiserror`] does.
+///
+/// [`thiserror`]: https://docs.rs/thiserror
+fn map_positional_placeholders(attr: &mut syn::Attribute, len: usize) -> Result<()> {
+    let args = attr.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
+    )?;
+    let lit = match args.first() {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        })) if args.len() == 1 => lit,
+        _ => return Ok(()),
+    };
+
+    let value = lit.value();
+    let mut fmt = String::with_capacity(value.len());
+    let mut rest = value.as_str();
+    while let Some(i) = rest.find(['{', '}']) {
+        let brace = &rest[i..=i];
+        fmt.push_str(&rest[..=i]);
+        rest = &rest[i + 1..];
+        if let Some(escaped) = rest.strip_prefix(brace) {
+            fmt.push_str(brace);
+            rest = escaped;
+        } else if brace == "{" {
+            let index = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+            let is_field = rest[..rest.len() - index.len()]
+                .parse::<usize>()
+                .map_or(false, |n| n < len);
+            if is_field && (index.starts_with(':') || index.starts_with('}')) {
+                fmt.push('_');
+            }
+        }
+    }
+    fmt.push_str(rest);
+
+    let lit = syn::LitStr::new(&fmt, lit.span());
+    attr.tokens = quote! { (#lit) };
+    Ok(())
+}
+
+/// Rendered parts of an `Error` derive: the `where` predicates of its `impl`
+/// block, the bodies of its `source()` and `provide()` methods, and the `From`
+/// impls of `#[error(from)]` fields.
+type Rendered = (
+    Vec<TokenStream>,
+    Option<TokenStream>,
+    Option<TokenStream>,
+    Vec<TokenStream>,
+);
+
+fn render_struct(type_params: &HashSet<syn::Ident>, state: &State) -> Result<Rendered> {
+    let transparent = state.default_info.info.transparent.unwrap_or_default();
+    let parsed_fields = parse_fields(type_params, state, transparent)?;
 
     let source = parsed_fields.render_source_as_struct();
     let provide = parsed_fields.render_provide_as_struct();
+    let from = parsed_fields.render_from()?;
+
+    let mut bounds = render_source_bounds(&parsed_fields.bounds);
+    bounds.extend(parsed_fields.provided_bounds);
 
-    Ok((parsed_fields.bounds, source, provide))
+    Ok((bounds, source, provide, from.into_iter().collect()))
 }
 
-fn render_enum(
-    type_params: &HashSet<syn::Ident>,
-    state: &State,
-) -> Result<(HashSet<syn::Type>, Option<TokenStream>, Option<TokenStream>)> {
+fn render_enum(type_params: &HashSet<syn::Ident>, state: &State) -> Result<Rendered> {
     let mut bounds = HashSet::default();
+    let mut provided_bounds = Vec::new();
     let mut source_match_arms = Vec::new();
     let mut provide_match_arms = Vec::new();
+    let mut forwarded_provide_match_arms = Vec::new();
+    let mut from_impls = Vec::new();
 
-    for variant in state.enabled_variant_data().variants {
+    let data = state.enabled_variant_data();
+    for (variant, info) in data.variants.into_iter().zip(data.infos) {
         let default_info = FullMetaInfo {
             enabled: true,
             ..FullMetaInfo::default()
@@ -126,17 +310,27 @@ fn render_enum(
             default_info,
         )?;
 
-        let parsed_fields = parse_fields(type_params, &state)?;
+        let transparent = info.info.transparent.unwrap_or_default();
+        let parsed_fields = parse_fields(type_params, &state, transparent)?;
 
         if let Some(expr) = parsed_fields.render_source_as_enum_variant_match_arm() {
             source_match_arms.push(expr);
         }
 
         if let Some(expr) = parsed_fields.render_provide_as_enum_variant_match_arm() {
-            provide_match_arms.push(expr);
+            // Forwarding to an unmarked transparent field alone doesn't require
+            // `provide()` to be generated, but it's forwarded whenever it is.
+            if parsed_fields.transparent && parsed_fields.backtrace.is_none() {
+                forwarded_provide_match_arms.push(expr);
+            } else {
+                provide_match_arms.push(expr);
+            }
         }
 
+        from_impls.extend(parsed_fields.render_from()?);
+
         bounds.extend(parsed_fields.bounds.into_iter());
+        provided_bounds.extend(parsed_fields.provided_bounds);
     }
 
     let render = |match_arms: &mut Vec<TokenStream>, unmatched| {
@@ -153,18 +347,42 @@ fn render_enum(
         })
     };
 
+    if !provide_match_arms.is_empty() {
+        provide_match_arms.extend(forwarded_provide_match_arms);
+    }
+
     let source = render(&mut source_match_arms, quote! { None });
     let provide = render(&mut provide_match_arms, quote! { () });
 
-    Ok((bounds, source, provide))
+    let mut bounds = render_source_bounds(&bounds);
+    bounds.extend(provided_bounds);
+
+    Ok((bounds, source, provide, from_impls))
+}
+
+/// Renders `where` predicates for the given types of `source` fields.
+fn render_source_bounds(bounds: &HashSet<syn::Type>) -> Vec<TokenStream> {
+    let krate = std_or_core();
+
+    bounds
+        .iter()
+        .map(|ty| {
+            quote! {
+                #ty: ::core::fmt::Debug
+                    + ::core::fmt::Display
+                    + #krate::error::Error
+                    + 'static
+            }
+        })
+        .collect()
 }
 
 fn allowed_attr_params() -> AttrParams {
     AttrParams {
         enum_: vec!["ignore"],
-        struct_: vec!["ignore"],
-        variant: vec!["ignore"],
-        field: vec!["ignore", "source", "backtrace"],
+        struct_: vec!["ignore", "transparent"],
+        variant: vec!["ignore", "transparent"],
+        field: vec!["ignore", "source", "backtrace", "from", "provide"],
     }
 }
 
@@ -172,7 +390,17 @@ struct ParsedFields<'input, 'state> {
     data: MultiFieldData<'input, 'state>,
     source: Option<usize>,
     backtrace: Option<usize>,
+    /// Field marked with `#[error(from)]`, which is the `source` one too.
+    from: Option<usize>,
+    /// Fields marked with `#[error(provide)]` or `#[error(provide(ref))]`.
+    provided: Vec<usize>,
+    /// `where` predicates for the types of `provided` fields using type
+    /// parameters.
+    provided_bounds: Vec<TokenStream>,
     bounds: HashSet<syn::Type>,
+    /// Whether the `source` field is marked with `#[error(transparent)]`,
+    /// so `source()` and `provide()` are forwarded to it.
+    transparent: bool,
 }
 
 impl<'input, 'state> ParsedFields<'input, 'state> {
@@ -181,84 +409,225 @@ impl<'input, 'state> ParsedFields<'input, 'state> {
             data,
             source: None,
             backtrace: None,
+            from: None,
+            provided: Vec::new(),
+            provided_bounds: Vec::new(),
             bounds: HashSet::default(),
+            transparent: false,
         }
     }
 }
 
 impl<'input, 'state> ParsedFields<'input, 'state> {
     fn render_source_as_struct(&self) -> Option<TokenStream> {
+        let krate = std_or_core();
+
         let source = self.source?;
         let ident = &self.data.members[source];
-        Some(render_some(quote! { &#ident }))
+        if self.transparent {
+            let field =
+                render_transparent(&self.data.fields[source].ty, quote! { &#ident });
+            return Some(quote! { #krate::error::Error::source(#field) });
+        }
+        Some(render_source(
+            &self.data.fields[source].ty,
+            quote! { &#ident },
+        ))
     }
 
     fn render_source_as_enum_variant_match_arm(&self) -> Option<TokenStream> {
+        let krate = std_or_core();
+
         let source = self.source?;
         let pattern = self.data.matcher(&[source], &[quote! { source }]);
-        let expr = render_some(quote! { source });
+        let expr = if self.transparent {
+            let field =
+                render_transparent(&self.data.fields[source].ty, quote! { source });
+            quote! { #krate::error::Error::source(#field) }
+        } else {
+            render_source(&self.data.fields[source].ty, quote! { source })
+        };
         Some(quote! { #pattern => #expr })
     }
 
     fn render_provide_as_struct(&self) -> Option<TokenStream> {
-        let backtrace = self.backtrace?;
+        let krate = std_or_core();
 
-        let source_provider = self.source.map(|source| {
-            let source_expr = &self.data.members[source];
-            quote! {
-                ::std::error::Error::provide(&#source_expr, demand);
-            }
-        });
-        let backtrace_provider = self
-            .source
-            .filter(|source| *source == backtrace)
-            .is_none()
-            .then(|| {
-                let backtrace_expr = &self.data.members[backtrace];
-                quote! {
-                    demand.provide_ref::<std::backtrace::Backtrace>(&#backtrace_expr);
+        if self.transparent {
+            // The single transparent field of a struct is the only reason to
+            // generate `provide()`, so it's forwarded only if the field is
+            // marked as a `backtrace` one.
+            let source = self
+                .source
+                .filter(|source| self.backtrace == Some(*source))?;
+            let member = &self.data.members[source];
+            let field =
+                render_transparent(&self.data.fields[source].ty, quote! { &#member });
+            return Some(quote! { #krate::error::Error::provide(#field, request); });
+        }
+
+        self.render_providers(|i| {
+            let member = &self.data.members[i];
+            quote! { &#member }
+        })
+    }
+
+    fn render_provide_as_enum_variant_match_arm(&self) -> Option<TokenStream> {
+        let krate = std_or_core();
+
+        if self.transparent {
+            let source = self.source?;
+            let pattern = self.data.matcher(&[source], &[quote! { source }]);
+            let field =
+                render_transparent(&self.data.fields[source].ty, quote! { source });
+            return Some(quote! {
+                #pattern => {
+                    #krate::error::Error::provide(#field, request);
                 }
             });
+        }
 
-        (source_provider.is_some() || backtrace_provider.is_some()).then(|| {
-            quote! {
-                #backtrace_provider
-                #source_provider
+        let binding = |i| {
+            if Some(i) == self.source {
+                quote! { source }
+            } else if Some(i) == self.backtrace {
+                quote! { backtrace }
+            } else {
+                format_ident!("field_{i}").into_token_stream()
+            }
+        };
+
+        let providers = self.render_providers(binding)?;
+
+        let mut indexes = self
+            .source
+            .into_iter()
+            .chain(self.backtrace)
+            .chain(self.provided.iter().copied())
+            .collect::<Vec<_>>();
+        indexes.sort_unstable();
+        indexes.dedup();
+        let bindings = indexes.iter().copied().map(binding).collect::<Vec<_>>();
+        let pattern = self.data.matcher(&indexes, &bindings);
+
+        Some(quote! {
+            #pattern => {
+                #providers
             }
         })
     }
 
-    fn render_provide_as_enum_variant_match_arm(&self) -> Option<TokenStream> {
-        let backtrace = self.backtrace?;
-
-        match self.source {
-            Some(source) if source == backtrace => {
-                let pattern = self.data.matcher(&[source], &[quote! { source }]);
-                Some(quote! {
-                    #pattern => {
-                        ::std::error::Error::provide(source, demand);
+    /// Renders statements of the `provide()` method body, providing the
+    /// `backtrace` and `#[error(provide)]` fields and forwarding to the `source`
+    /// one, with the fields referenced by the given `expr`.
+    ///
+    /// Renders nothing if there are neither `backtrace` nor `#[error(provide)]`
+    /// fields.
+    fn render_providers(
+        &self,
+        expr: impl Fn(usize) -> TokenStream,
+    ) -> Option<TokenStream> {
+        if self.backtrace.is_none() && self.provided.is_empty() {
+            return None;
+        }
+
+        let backtrace_provider = self
+            .backtrace
+            .filter(|backtrace| Some(*backtrace) != self.source)
+            .map(|backtrace| {
+                let backtrace_expr = expr(backtrace);
+                if is_option_type(&self.data.fields[backtrace].ty) {
+                    quote! {
+                        if let Some(backtrace) = #backtrace_expr {
+                            request.provide_ref::<::std::backtrace::Backtrace>(backtrace);
+                        }
                     }
-                })
-            }
-            Some(source) => {
-                let pattern = self.data.matcher(
-                    &[source, backtrace],
-                    &[quote! { source }, quote! { backtrace }],
-                );
-                Some(quote! {
-                    #pattern => {
-                        demand.provide_ref::<std::backtrace::Backtrace>(backtrace);
-                        ::std::error::Error::provide(source, demand);
+                } else {
+                    quote! {
+                        request.provide_ref::<::std::backtrace::Backtrace>(#backtrace_expr);
                     }
-                })
+                }
+            });
+        let field_providers = self.provided.iter().map(|&i| {
+            let ty = &self.data.fields[i].ty;
+            let field_expr = expr(i);
+            if self.data.infos[i].info.provide_ref == Some(true) {
+                quote! { request.provide_ref::<#ty>(#field_expr); }
+            } else {
+                quote! {
+                    request.provide_value_with::<#ty>(|| {
+                        ::core::clone::Clone::clone(#field_expr)
+                    });
+                }
             }
-            None => {
-                let pattern = self.data.matcher(&[backtrace], &[quote! { backtrace }]);
-                Some(quote! {
-                    #pattern => {
-                        demand.provide_ref::<std::backtrace::Backtrace>(backtrace);
+        });
+        let source_provider = self
+            .source
+            .map(|source| self.render_source_provider(source, expr(source)));
+
+        Some(quote! {
+            #backtrace_provider
+            #( #field_providers )*
+            #source_provider
+        })
+    }
+
+    /// Renders a `From` implementation converting the `#[error(from)]` field
+    /// into the struct or enum variant, capturing a new `Backtrace` for the
+    /// `backtrace` field, if any.
+    fn render_from(&self) -> Result<Option<TokenStream>> {
+        let Some(from) = self.from else {
+            return Ok(None);
+        };
+
+        let initializers =
+            self.data
+                .state
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    match self.data.field_indexes.iter().position(|index| *index == i) {
+                    Some(index) if index == from => Ok(from::original_value(0, 1)),
+                    // Fields of `Backtrace` type are captured even if they're
+                    // not provided as the `backtrace`.
+                    Some(index)
+                        if Some(index) == self.backtrace
+                            || cfg!(feature = "std") && is_backtrace_type(&field.ty) =>
+                    {
+                        Ok(render_backtrace_capture(&field.ty))
                     }
+                    _ => Err(Error::new(
+                        field.span(),
+                        "`#[error(from)]` requires no other fields than `source` and \
+                         `backtrace` ones",
+                    )),
+                }
                 })
+                .collect::<Result<Vec<_>>>()?;
+
+        let ty = &self.data.fields[from].ty;
+        Ok(Some(from::render_from_impl(
+            &self.data,
+            &self.data.state.input.generics,
+            &[ty.to_token_stream()],
+            &initializers,
+        )))
+    }
+
+    /// Renders a statement forwarding `provide()` to the `source` field
+    /// referenced by `expr`, if the field has a value.
+    fn render_source_provider(&self, source: usize, expr: TokenStream) -> TokenStream {
+        let krate = std_or_core();
+
+        let ty = &self.data.fields[source].ty;
+        if wrapped_type(ty).is_none() {
+            return quote! { #krate::error::Error::provide(#expr, request); };
+        }
+        let source_expr = render_source(ty, expr);
+        quote! {
+            if let Some(source) = #source_expr {
+                #krate::error::Error::provide(source, request);
             }
         }
     }
@@ -268,14 +637,144 @@ fn render_some<T>(expr: T) -> TokenStream
 where
     T: quote::ToTokens,
 {
-    quote! { Some(#expr as &(dyn ::std::error::Error + 'static)) }
+    let krate = std_or_core();
+    quote! { Some(#expr as &(dyn #krate::error::Error + 'static)) }
+}
+
+/// Renders an `Option<&dyn Error>` expression of the `source` field of the
+/// given type, referenced by `expr`, looking through the [`WRAPPER_TYPES`].
+fn render_source(ty: &syn::Type, expr: TokenStream) -> TokenStream {
+    match wrapped_type(ty) {
+        Some(("Option", inner)) => {
+            let inner = render_source(inner, quote! { source });
+            quote! { (#expr).as_ref().and_then(|source| #inner) }
+        }
+        Some((_, inner)) => render_source(inner, quote! { &**#expr }),
+        None => render_some(expr),
+    }
+}
+
+/// Renders a reference to the actual error of the transparent field of the given
+/// type, referenced by `expr`, looking through the [`WRAPPER_TYPES`] other than
+/// `Option`, so `Box<dyn Error + Send + Sync>` fields are forwarded to as well.
+fn render_transparent(ty: &syn::Type, expr: TokenStream) -> TokenStream {
+    match wrapped_type(ty) {
+        Some((name, inner)) if name != "Option" => {
+            render_transparent(inner, quote! { &**#expr })
+        }
+        _ => expr,
+    }
+}
+
+/// Renders a `Backtrace::capture()` expression filling the `backtrace` field
+/// of the given type, leaving `Option<Backtrace>` one as `None` if capturing is
+/// disabled.
+fn render_backtrace_capture(ty: &syn::Type) -> TokenStream {
+    if is_option_type(ty) {
+        quote! {{
+            let backtrace = ::std::backtrace::Backtrace::capture();
+            match backtrace.status() {
+                ::std::backtrace::BacktraceStatus::Captured => Some(backtrace),
+                _ => None,
+            }
+        }}
+    } else {
+        quote! { ::std::backtrace::Backtrace::capture() }
+    }
+}
+
+/// Checks whether the given type is a `Backtrace` (detected by the last segment
+/// of its type path) or an `Option` of it.
+fn is_backtrace_type(ty: &syn::Type) -> bool {
+    let ty = match wrapped_type(ty) {
+        Some(("Option", inner)) => inner,
+        _ => ty,
+    };
+    utils::is_type_path_ends_with_segment(ty, "Backtrace")
+}
+
+/// Checks whether the given type is an `Option`.
+fn is_option_type(ty: &syn::Type) -> bool {
+    matches!(wrapped_type(ty), Some(("Option", _)))
+}
+
+/// Names of the types wrapping the `source` field, which are looked through
+/// to get the actual source error.
+const WRAPPER_TYPES: &[&str] = &["Option", "Box", "Rc", "Arc"];
+
+/// Returns the name of the [`WRAPPER_TYPES`] the given type is, along with the
+/// type it wraps, if it's any of them.
+fn wrapped_type(ty: &syn::Type) -> Option<(&'static str, &syn::Type)> {
+    let syn::Type::Path(ty) = ty else {
+        return None;
+    };
+    let segment = ty.path.segments.last()?;
+    let name = WRAPPER_TYPES.iter().find(|name| segment.ident == name)?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>()[..] {
+        [syn::GenericArgument::Type(inner)] => Some((name, inner)),
+        _ => None,
+    }
+}
+
+/// Returns the actual source error type of the `source` field of the given
+/// type, looking through the [`WRAPPER_TYPES`].
+fn source_type(ty: &syn::Type) -> &syn::Type {
+    wrapped_type(ty).map_or(ty, |(_, inner)| source_type(inner))
+}
+
+/// Returns the actual error type of the transparent field of the given type,
+/// looking through the [`WRAPPER_TYPES`] other than `Option`.
+fn transparent_type(ty: &syn::Type) -> &syn::Type {
+    match wrapped_type(ty) {
+        Some((name, inner)) if name != "Option" => transparent_type(inner),
+        _ => ty,
+    }
+}
+
+/// Returns the path to the crate the `Error` trait is used from: `::std` with
+/// the `std` feature, or `::core` otherwise.
+fn std_or_core() -> TokenStream {
+    if cfg!(feature = "std") {
+        quote! { ::std }
+    } else {
+        quote! { ::core }
+    }
 }
 
 fn parse_fields<'input, 'state>(
     type_params: &HashSet<syn::Ident>,
     state: &'state State<'input>,
+    transparent: bool,
 ) -> Result<ParsedFields<'input, 'state>> {
     let mut parsed_fields = match state.derive_type {
+        _ if transparent => {
+            if state.fields.len() != 1 {
+                return Err(Error::new(
+                    state
+                        .variant
+                        .map_or_else(|| state.input.span(), |v| v.span()),
+                    "`#[error(transparent)]` requires exactly one field",
+                ));
+            }
+            let mut parsed_fields = ParsedFields::new(state.enabled_fields_data());
+            parsed_fields.source = Some(0);
+            parsed_fields.transparent = true;
+            let info = parsed_fields.data.infos.first().map(|info| &info.info);
+            parsed_fields.from = info
+                .and_then(|info| info.from)
+                .filter(|from| *from)
+                .map(|_| 0);
+            // `Backtrace` is available in `std` only.
+            parsed_fields.backtrace = info
+                .and_then(|info| info.backtrace)
+                .filter(|backtrace| *backtrace && cfg!(feature = "std"))
+                .map(|_| 0);
+            Ok(parsed_fields)
+        }
+
         DeriveType::Named => {
             parse_fields_impl(state, |attr, field, _| {
                 // Unwrapping is safe, cause fields in named struct
@@ -284,10 +783,7 @@ fn parse_fields<'input, 'state>(
 
                 match attr {
                     "source" => ident == "source",
-                    "backtrace" => {
-                        ident == "backtrace"
-                            || is_type_path_ends_with_segment(&field.ty, "Backtrace")
-                    }
+                    "backtrace" => ident == "backtrace" || is_backtrace_type(&field.ty),
                     _ => unreachable!(),
                 }
             })
@@ -296,13 +792,8 @@ fn parse_fields<'input, 'state>(
         DeriveType::Unnamed => {
             let mut parsed_fields =
                 parse_fields_impl(state, |attr, field, len| match attr {
-                    "source" => {
-                        len == 1
-                            && !is_type_path_ends_with_segment(&field.ty, "Backtrace")
-                    }
-                    "backtrace" => {
-                        is_type_path_ends_with_segment(&field.ty, "Backtrace")
-                    }
+                    "source" => len == 1 && !is_backtrace_type(&field.ty),
+                    "backtrace" => is_backtrace_type(&field.ty),
                     _ => unreachable!(),
                 })?;
 
@@ -316,35 +807,50 @@ fn parse_fields<'input, 'state>(
         _ => unreachable!(),
     }?;
 
+    if !parsed_fields.transparent {
+        parsed_fields.provided = parsed_fields
+            .data
+            .infos
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.info.provide == Some(true))
+            .map(|(i, _)| i)
+            .collect();
+        parsed_fields.provided_bounds = parsed_fields
+            .provided
+            .iter()
+            .filter_map(|&i| {
+                let ty = &parsed_fields.data.fields[i].ty;
+                if !utils::is_type_parameter_used_in_type(type_params, ty) {
+                    return None;
+                }
+                Some(
+                    if parsed_fields.data.infos[i].info.provide_ref == Some(true) {
+                        quote! { #ty: 'static }
+                    } else {
+                        quote! { #ty: ::core::clone::Clone + 'static }
+                    },
+                )
+            })
+            .collect();
+    }
+
     if let Some(source) = parsed_fields.source {
+        let ty = &state.fields[source].ty;
         add_bound_if_type_parameter_used_in_type(
             &mut parsed_fields.bounds,
             type_params,
-            &state.fields[source].ty,
+            if parsed_fields.transparent {
+                transparent_type(ty)
+            } else {
+                source_type(ty)
+            },
         );
     }
 
     Ok(parsed_fields)
 }
 
-/// Checks if `ty` is [`syn::Type::Path`] and ends with segment matching `tail`
-/// and doesn't contain any generic parameters.
-fn is_type_path_ends_with_segment(ty: &syn::Type, tail: &str) -> bool {
-    let syn::Type::Path(ty) = ty else {
-        return false;
-    };
-
-    // Unwrapping is safe, cause 'syn::TypePath.path.segments'
-    // have to have at least one segment
-    let segment = ty.path.segments.last().unwrap();
-
-    if !matches!(segment.arguments, syn::PathArguments::None) {
-        return false;
-    }
-
-    segment.ident == tail
-}
-
 fn infer_source_field(
     fields: &[&syn::Field],
     parsed_fields: &ParsedFields,
@@ -392,17 +898,29 @@ where
         state.fields.len(),
         iter.clone(),
         "source",
-        |info| info.source,
+        // `#[error(from)]` field is a `source` one too.
+        |info| info.from.filter(|from| *from).or(info.source),
     )?;
 
-    let backtrace = parse_field_impl(
-        &is_valid_default_field_for_attr,
-        state.fields.len(),
-        iter.clone(),
-        "backtrace",
-        |info| info.backtrace,
+    let from = assert_iter_contains_zero_or_one_item(
+        iter.clone().filter(|(_, _, info)| info.from == Some(true)),
+        "Multiple `from` attributes specified. \
+         Single attribute per struct/enum variant allowed.",
     )?;
 
+    // `Backtrace` is available in `std` only.
+    let backtrace = if cfg!(feature = "std") {
+        parse_field_impl(
+            &is_valid_default_field_for_attr,
+            state.fields.len(),
+            iter.clone(),
+            "backtrace",
+            |info| info.backtrace,
+        )?
+    } else {
+        None
+    };
+
     let mut parsed_fields = ParsedFields::new(state.enabled_fields_data());
 
     if let Some((index, _, _)) = source {
@@ -413,6 +931,10 @@ where
         parsed_fields.backtrace = Some(index);
     }
 
+    if let Some((index, _, _)) = from {
+        parsed_fields.from = Some(index);
+    }
+
     Ok(parsed_fields)
 }
 
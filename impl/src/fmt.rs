@@ -0,0 +1,126 @@
+//! Implementation of a [`Fmt`] derive macro.
+//!
+//! [`Fmt`]: macro@crate::Fmt
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{punctuated::Punctuated, spanned::Spanned as _, Error, Result};
+
+use crate::utils::{self, HashSet};
+
+/// Names of the [`core::fmt`] traits forwarded by the [`Fmt`] derive by default.
+///
+/// [`Fmt`]: macro@crate::Fmt
+const DEFAULT_TRAITS: &[&str] = &[
+    "Display", "Debug", "Binary", "Octal", "LowerHex", "UpperHex", "LowerExp",
+    "UpperExp",
+];
+
+/// Names of all the [`core::fmt`] traits, which may be forwarded by the [`Fmt`]
+/// derive via `#[fmt(...)]` attribute.
+///
+/// [`Fmt`]: macro@crate::Fmt
+const TRAITS: &[&str] = &[
+    "Display", "Debug", "Binary", "Octal", "LowerHex", "UpperHex", "LowerExp",
+    "UpperExp", "Pointer",
+];
+
+/// Expands a [`Fmt`] derive macro.
+///
+/// [`Fmt`]: macro@crate::Fmt
+pub fn expand(input: &syn::DeriveInput, _: &str) -> Result<TokenStream> {
+    let error = || {
+        Error::new(
+            input.span(),
+            "`Fmt` can only be derived for structs with a single field",
+        )
+    };
+    let syn::Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(error());
+    };
+    let member = field
+        .ident
+        .clone()
+        .map_or_else(|| syn::Member::Unnamed(0.into()), syn::Member::Named);
+    let field_ty = &field.ty;
+    let name = &input.ident;
+
+    let type_params: HashSet<_> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let is_generic = utils::is_type_parameter_used_in_type(&type_params, field_ty);
+
+    Ok(parse_traits(&input.attrs)?
+        .into_iter()
+        .map(|trait_ident| {
+            // Bounding a concrete field type by a trait it doesn't implement is
+            // an error, unless the bound is higher-ranked, making the impl
+            // allowed (but unusable) in such case.
+            let bound = if is_generic {
+                quote! { where #field_ty: ::core::fmt::#trait_ident }
+            } else {
+                quote! {
+                    where for<'__derive_more_fmt> #field_ty: ::core::fmt::#trait_ident
+                }
+            };
+            let generics = utils::add_extra_where_clauses(&input.generics, bound);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::fmt::#trait_ident for #name #ty_generics
+                     #where_clause
+                {
+                    #[inline]
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        ::core::fmt::#trait_ident::fmt(&self.#member, f)
+                    }
+                }
+            }
+        })
+        .collect())
+}
+
+/// Parses the formatting traits to be forwarded from the `#[fmt(Trait, ...)]`
+/// attribute, defaulting to the [`DEFAULT_TRAITS`] if there is none.
+fn parse_traits(attrs: &[syn::Attribute]) -> Result<Vec<syn::Ident>> {
+    let mut attrs = attrs.iter().filter(|attr| attr.path.is_ident("fmt"));
+    let Some(attr) = attrs.next() else {
+        return Ok(DEFAULT_TRAITS
+            .iter()
+            .map(|trait_name| format_ident!("{trait_name}"))
+            .collect());
+    };
+    if let Some(another) = attrs.next() {
+        return Err(Error::new(another.span(), "Too many attributes specified"));
+    }
+
+    let idents = attr
+        .parse_args_with(Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated)?;
+    let mut traits: Vec<syn::Ident> = Vec::with_capacity(idents.len());
+    for ident in idents {
+        if !TRAITS.iter().any(|trait_name| ident == trait_name) {
+            return Err(Error::new(
+                ident.span(),
+                format!(
+                    "unknown formatting trait, expected one of: {}",
+                    TRAITS.join(", "),
+                ),
+            ));
+        }
+        if traits.contains(&ident) {
+            return Err(Error::new(ident.span(), "duplicated formatting trait"));
+        }
+        traits.push(ident);
+    }
+    Ok(traits)
+}
@@ -1,15 +1,22 @@
+#[cfg(feature = "from")]
 use std::iter;
 
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
-use syn::{parse::Result, DeriveInput, Index};
+#[cfg(feature = "from")]
+use quote::format_ident;
+use quote::{quote, ToTokens};
+use syn::Index;
+#[cfg(feature = "from")]
+use syn::{parse::Result, DeriveInput};
 
+use crate::utils::MultiFieldData;
+#[cfg(feature = "from")]
 use crate::utils::{
-    add_where_clauses_for_new_ident, AttrParams, DeriveType, HashMap, MultiFieldData,
-    RefType, State,
+    add_where_clauses_for_new_ident, AttrParams, DeriveType, HashMap, RefType, State,
 };
 
 /// Provides the hook to expand `#[derive(From)]` into an implementation of `From`
+#[cfg(feature = "from")]
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
     let state = State::with_attr_params(
         input,
@@ -30,13 +37,13 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     }
 }
 
+#[cfg(feature = "from")]
 pub fn struct_from(input: &DeriveInput, state: &State) -> TokenStream {
     let multi_field_data = state.enabled_fields_data();
     let MultiFieldData {
         fields,
         variant_info,
         infos,
-        input_type,
         trait_path,
         ..
     } = multi_field_data.clone();
@@ -50,12 +57,7 @@ pub fn struct_from(input: &DeriveInput, state: &State) -> TokenStream {
         let mut from_types = Vec::with_capacity(infos.len());
         for (i, (info, field)) in infos.iter().zip(fields.iter()).enumerate() {
             let field_type = &field.ty;
-            let variable = if fields.len() == 1 {
-                quote! { original }
-            } else {
-                let tuple_index = Index::from(i);
-                quote! { original.#tuple_index }
-            };
+            let variable = original_value(i, fields.len());
             if let Some(type_) = explicit_type {
                 initializers.push(quote! {
                     <#field_type as #trait_path<#type_>>::from(#variable)
@@ -83,26 +85,56 @@ pub fn struct_from(input: &DeriveInput, state: &State) -> TokenStream {
             }
         }
 
-        let body = multi_field_data.initializer(&initializers);
-        let (impl_generics, _, where_clause) = new_generics.split_for_impl();
-        let (_, ty_generics, _) = input.generics.split_for_impl();
+        impls.push(render_from_impl(
+            &multi_field_data,
+            &new_generics,
+            &from_types,
+            &initializers,
+        ));
+    }
 
-        impls.push(quote! {
-            #[automatically_derived]
-            impl #impl_generics #trait_path<(#(#from_types),*)> for
-                #input_type #ty_generics #where_clause {
+    quote! { #( #impls )* }
+}
 
-                #[inline]
-                fn from(original: (#(#from_types),*)) -> #input_type #ty_generics {
-                    #body
-                }
-            }
-        });
+/// Renders the expression of the `index`th one of the `len` values a struct or
+/// an enum variant is converted from in a `From` implementation.
+pub(crate) fn original_value(index: usize, len: usize) -> TokenStream {
+    if len == 1 {
+        quote! { original }
+    } else {
+        let tuple_index = Index::from(index);
+        quote! { original.#tuple_index }
     }
+}
 
-    quote! { #( #impls )* }
+/// Renders a `From` implementation converting the `from_types` into the struct
+/// or enum variant of the given `data`, initializing its fields with the given
+/// `initializers` referring the converted values via [`original_value()`].
+pub(crate) fn render_from_impl<T: ToTokens>(
+    data: &MultiFieldData,
+    generics: &syn::Generics,
+    from_types: &[TokenStream],
+    initializers: &[T],
+) -> TokenStream {
+    let input_type = data.input_type;
+    let body = data.initializer(initializers);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = data.state.input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<(#(#from_types),*)> for
+            #input_type #ty_generics #where_clause {
+
+            #[inline]
+            fn from(original: (#(#from_types),*)) -> #input_type #ty_generics {
+                #body
+            }
+        }
+    }
 }
 
+#[cfg(feature = "from")]
 fn enum_from(input: &DeriveInput, state: State) -> TokenStream {
     let mut tokens = TokenStream::new();
 
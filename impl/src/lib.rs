@@ -25,21 +25,27 @@ mod add_like;
 mod as_mut;
 #[cfg(feature = "as_ref")]
 mod as_ref;
+#[cfg(feature = "as_static_str")]
+mod as_static_str;
 #[cfg(feature = "as_variant")]
 mod as_variant;
 #[cfg(feature = "as_variant_mut")]
 mod as_variant_mut;
 #[cfg(feature = "constructor")]
 mod constructor;
+#[cfg(feature = "debug")]
+mod debug;
 #[cfg(feature = "deref")]
 mod deref;
 #[cfg(feature = "deref_mut")]
 mod deref_mut;
-#[cfg(feature = "display")]
+#[cfg(any(feature = "display", feature = "error"))]
 mod display;
 #[cfg(feature = "error")]
 mod error;
-#[cfg(feature = "from")]
+#[cfg(feature = "fmt")]
+mod fmt;
+#[cfg(any(feature = "error", feature = "from"))]
 mod from;
 #[cfg(feature = "from_str")]
 mod from_str;
@@ -61,8 +67,10 @@ mod mul_helpers;
 mod mul_like;
 #[cfg(feature = "not")]
 mod not_like;
-#[cfg(feature = "display")]
+#[cfg(any(feature = "debug", feature = "display", feature = "error"))]
 mod parsing;
+#[cfg(any(feature = "as_static_str", feature = "display", feature = "error"))]
+mod rename;
 #[cfg(feature = "sum")]
 mod sum_like;
 #[cfg(feature = "try_into")]
@@ -186,7 +194,7 @@ create_derive!(
 create_derive!("sum", sum_like, Sum, sum_derive);
 create_derive!("sum", sum_like, Product, product_derive);
 
-create_derive!("error", error, Error, error_derive, error);
+create_derive!("error", error, Error, error_derive, error, display);
 
 create_derive!("from_str", from_str, FromStr, from_str_derive);
 
@@ -200,6 +208,10 @@ create_derive!("display", display, UpperExp, upper_exp_derive, upper_exp);
 create_derive!("display", display, Pointer, pointer_derive, pointer);
 create_derive!("display", display, DebugCustom, debug_custom_derive, debug);
 
+create_derive!("debug", debug, Debug, debug_derive, debug);
+
+create_derive!("fmt", fmt, Fmt, fmt_derive, fmt);
+
 create_derive!("index", index, Index, index_derive, index);
 create_derive!(
     "index_mut",
@@ -230,6 +242,14 @@ create_derive!(
 
 create_derive!("as_ref", as_ref, AsRef, as_ref_derive, as_ref);
 create_derive!("as_mut", as_mut, AsMut, as_mut_derive, as_mut);
+create_derive!(
+    "as_static_str",
+    as_static_str,
+    AsStaticStr,
+    as_static_str_derive,
+    as_static_str,
+    display
+);
 
 create_derive!(
     "as_variant",
@@ -0,0 +1,39 @@
+//! Parsing of `rename_all` rules, shared by derives printing names of variants.
+
+use convert_case::Case;
+use syn::{Error, Result};
+
+/// Allowed values of `rename_all = "..."` attribute argument.
+pub(crate) const ALLOWED_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Parses the given `rename_all = "..."` rule into a [`Case`].
+pub(crate) fn parse_rule(lit: &syn::LitStr) -> Result<Case> {
+    Ok(match lit.value().as_str() {
+        "lowercase" => Case::Flat,
+        "UPPERCASE" => Case::UpperFlat,
+        "PascalCase" => Case::Pascal,
+        "camelCase" => Case::Camel,
+        "snake_case" => Case::Snake,
+        "SCREAMING_SNAKE_CASE" => Case::ScreamingSnake,
+        "kebab-case" => Case::Kebab,
+        "SCREAMING-KEBAB-CASE" => Case::Cobol,
+        _ => {
+            return Err(Error::new(
+                lit.span(),
+                format!(
+                    "Unknown `rename_all` rule. Allowed rules are: {}",
+                    ALLOWED_RULES.join(", "),
+                ),
+            ))
+        }
+    })
+}
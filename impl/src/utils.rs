@@ -994,6 +994,25 @@ fn parse_punctuated_nested_meta(
                     (None, "owned") => info.owned = Some(true),
                     (None, "ref") => info.ref_ = Some(true),
                     (None, "ref_mut") => info.ref_mut = Some(true),
+                    (None, "provide") => {
+                        parse_nested = false;
+                        info.provide = Some(true);
+                        for meta in &list.nested {
+                            if !matches!(
+                                meta,
+                                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("ref"),
+                            ) {
+                                return Err(Error::new(
+                                    meta.span(),
+                                    format!(
+                                        "Attribute doesn't support `{}` parameter here",
+                                        quote! { #meta },
+                                    ),
+                                ));
+                            }
+                            info.provide_ref = Some(true);
+                        }
+                    }
 
                     #[cfg(any(feature = "from", feature = "into"))]
                     (None, "types")
@@ -1096,6 +1115,9 @@ fn parse_punctuated_nested_meta(
                     (Some("not"), "source") => info.source = Some(false),
                     (None, "backtrace") => info.backtrace = Some(true),
                     (Some("not"), "backtrace") => info.backtrace = Some(false),
+                    (None, "transparent") => info.transparent = Some(true),
+                    (None, "from") => info.from = Some(true),
+                    (None, "provide") => info.provide = Some(true),
                     _ => {
                         return Err(Error::new(
                             path.span(),
@@ -1139,6 +1161,10 @@ pub struct MetaInfo {
     pub ref_mut: Option<bool>,
     pub source: Option<bool>,
     pub backtrace: Option<bool>,
+    pub transparent: Option<bool>,
+    pub from: Option<bool>,
+    pub provide: Option<bool>,
+    pub provide_ref: Option<bool>,
     #[cfg(any(feature = "from", feature = "into"))]
     pub types: HashMap<RefType, HashSet<syn::Type>>,
 }
@@ -1177,6 +1203,24 @@ impl FullMetaInfo {
     }
 }
 
+/// Checks if `ty` is [`syn::Type::Path`] and ends with segment matching `tail`
+/// and doesn't contain any generic parameters.
+pub fn is_type_path_ends_with_segment(ty: &syn::Type, tail: &str) -> bool {
+    let syn::Type::Path(ty) = ty else {
+        return false;
+    };
+
+    // Unwrapping is safe, cause 'syn::TypePath.path.segments'
+    // have to have at least one segment
+    let segment = ty.path.segments.last().unwrap();
+
+    if !matches!(segment.arguments, syn::PathArguments::None) {
+        return false;
+    }
+
+    segment.ident == tail
+}
+
 pub fn get_if_type_parameter_used_in_type(
     type_parameters: &HashSet<syn::Ident>,
     ty: &syn::Type,
@@ -9,6 +9,8 @@
 //! [`AsRef`]: crate::AsRef
 //!
 //! [`Display`-like]: crate::Display
+//! [`Debug`]: crate::Debug
+//! [`Fmt`]: crate::Fmt
 //!
 //! [`Error`]: crate::Error
 //!
@@ -28,6 +30,7 @@
 //! [`AsVariantMut`]: crate::AsVariantMut
 //! [`IsVariant`]: crate::IsVariant
 //! [`Unwrap`]: crate::Unwrap
+//! [`AsStaticStr`]: crate::AsStaticStr
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![deny(rustdoc::broken_intra_doc_links, rustdoc::private_intra_doc_links)]
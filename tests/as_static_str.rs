@@ -0,0 +1,78 @@
+#![no_std]
+#![allow(dead_code)]
+
+use derive_more::AsStaticStr;
+
+#[derive(AsStaticStr)]
+enum Empty {}
+
+#[derive(AsStaticStr)]
+enum Status {
+    Active,
+    r#Inactive,
+    Suspended(),
+    Deleted {},
+}
+
+#[derive(AsStaticStr)]
+#[as_static_str(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Level {
+    Debug,
+    InfoMessage,
+    #[as_static_str(rename = "warn!")]
+    Warn,
+}
+
+#[derive(AsStaticStr)]
+#[display(rename_all = "kebab-case")]
+#[display("<{_variant}>")]
+enum Method {
+    GetAll,
+    #[display(rename = "POST")]
+    Post,
+    #[display("put")]
+    PutOne,
+}
+
+#[derive(AsStaticStr)]
+#[display(rename_all = "lowercase")]
+#[as_static_str(rename_all = "snake_case")]
+enum Overridden {
+    FirstOne,
+    #[display(rename = "2nd")]
+    #[as_static_str(rename = "second")]
+    SecondOne,
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Status::Active.as_str(), "Active");
+    assert_eq!(Status::r#Inactive.as_str(), "Inactive");
+    assert_eq!(Status::Suspended().as_str(), "Suspended");
+    assert_eq!(Status::Deleted {}.as_str(), "Deleted");
+    assert_eq!(Level::Debug.as_str(), "DEBUG");
+    assert_eq!(Level::InfoMessage.as_str(), "INFO_MESSAGE");
+    assert_eq!(Level::Warn.as_str(), "warn!");
+}
+
+#[test]
+fn display_rename() {
+    assert_eq!(Method::GetAll.as_str(), "get-all");
+    assert_eq!(Method::Post.as_str(), "POST");
+    assert_eq!(Method::PutOne.as_str(), "put-one");
+    assert_eq!(Overridden::FirstOne.as_str(), "first_one");
+    assert_eq!(Overridden::SecondOne.as_str(), "second");
+}
+
+#[test]
+fn as_ref() {
+    assert_eq!(AsRef::<str>::as_ref(&Status::Active), "Active");
+    assert_eq!(Level::InfoMessage.as_ref(), "INFO_MESSAGE");
+}
+
+#[test]
+fn into_static_str() {
+    let name: &'static str = Level::Warn.into();
+    assert_eq!(name, "warn!");
+    assert_eq!(<&str>::from(Status::Active), "Active");
+}
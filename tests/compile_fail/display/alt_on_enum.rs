@@ -0,0 +1,7 @@
+#[derive(derive_more::Display)]
+#[display(alt = "Foo: {}")]
+enum Foo {
+    Bar(u8),
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::Display)]
+struct Digest(#[display(bytes)] [u8; 4]);
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Display)]
+#[display("{a}")]
+#[display(delimiter = ", ")]
+pub struct Foo {
+    a: i32,
+    b: i32,
+}
+
+fn main() {}
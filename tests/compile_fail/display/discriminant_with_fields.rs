@@ -0,0 +1,9 @@
+#[derive(derive_more::Display)]
+#[display(discriminant)]
+#[repr(u8)]
+enum Foo {
+    Bar,
+    Baz(u8),
+}
+
+fn main() {}
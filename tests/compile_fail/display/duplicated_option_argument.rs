@@ -0,0 +1,4 @@
+#[derive(derive_more::Display)]
+pub struct Foo(#[display(none = "a", none = "b")] Option<i32>);
+
+fn main() {}
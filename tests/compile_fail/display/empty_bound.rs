@@ -0,0 +1,6 @@
+#[derive(derive_more::Display)]
+#[display(bound())]
+#[display("{}", _0)]
+pub struct Foo<T>(T);
+
+fn main() {}
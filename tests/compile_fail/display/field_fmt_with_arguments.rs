@@ -0,0 +1,9 @@
+#[derive(derive_more::Display)]
+#[display("{a}")]
+pub struct Foo {
+    #[display("{} {}", b)]
+    a: i32,
+    b: i32,
+}
+
+fn main() {}
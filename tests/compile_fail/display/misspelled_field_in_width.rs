@@ -0,0 +1,7 @@
+#[derive(derive_more::Display)]
+enum Error {
+    #[display("{code:>width$}")]
+    Code { code: u16, widht: usize },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Display)]
+pub struct Foo {
+    #[display(forward)]
+    a: i32,
+    #[display(forward)]
+    b: i32,
+}
+
+fn main() {}
@@ -1,5 +1,5 @@
 #[derive(derive_more::Display)]
-#[display("Stuff({})", bar)]
+#[display(bar)]
 pub struct Foo {
     bar: String,
 }
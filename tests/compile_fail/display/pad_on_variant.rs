@@ -0,0 +1,8 @@
+#[derive(derive_more::Display)]
+enum Foo {
+    #[display(pad)]
+    #[display("bar")]
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Display)]
+pub enum Foo {
+    #[display(rename = "bar")]
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Display)]
+#[display(rename_all = "Snake_Case")]
+pub enum Foo {
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Display)]
+#[display("{_type}::{_variant}")]
+pub struct Foo {
+    bar: i32,
+}
+
+fn main() {}
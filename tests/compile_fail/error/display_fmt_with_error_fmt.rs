@@ -0,0 +1,8 @@
+#[derive(Debug, derive_more::Error)]
+#[error("error {a}")]
+#[display("display {a}")]
+pub struct Foo {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(Debug, derive_more::Error)]
+pub enum Foo {
+    #[error(transparent)]
+    #[display("wrapped")]
+    Wrapped(std::io::Error),
+}
+
+fn main() {}
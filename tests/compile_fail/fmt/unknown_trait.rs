@@ -0,0 +1,5 @@
+#[derive(derive_more::Fmt)]
+#[fmt(Display, Write)]
+pub struct Foo(i32);
+
+fn main() {}
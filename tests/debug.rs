@@ -0,0 +1,172 @@
+#![allow(dead_code)]
+
+use derive_more::Debug;
+
+mod structs {
+    use super::Debug;
+
+    #[derive(Debug)]
+    struct Unit;
+
+    #[derive(Debug)]
+    struct Tuple(i32, &'static str);
+
+    #[derive(Debug)]
+    struct Struct {
+        a: i32,
+        b: &'static str,
+    }
+
+    #[derive(Debug)]
+    struct Empty {}
+
+    #[test]
+    fn assert() {
+        assert_eq!(format!("{:?}", Unit), "Unit");
+        assert_eq!(format!("{:?}", Tuple(1, "a")), r#"Tuple(1, "a")"#);
+        assert_eq!(
+            format!("{:?}", Struct { a: 1, b: "a" }),
+            r#"Struct { a: 1, b: "a" }"#
+        );
+        assert_eq!(format!("{:?}", Empty {}), "Empty");
+        assert_eq!(
+            format!("{:#?}", Struct { a: 1, b: "a" }),
+            "Struct {\n    a: 1,\n    b: \"a\",\n}",
+        );
+    }
+}
+
+mod field_attrs {
+    use super::Debug;
+
+    #[derive(Debug)]
+    struct Config {
+        name: &'static str,
+        #[debug(redact)]
+        password: &'static str,
+        #[debug("{:#x}")]
+        flags: u8,
+        #[debug(fmt = "{} bytes")]
+        size: usize,
+        #[debug(skip)]
+        buffer: Vec<u8>,
+    }
+
+    #[derive(Debug)]
+    struct AllSkipped {
+        #[debug(skip)]
+        a: i32,
+    }
+
+    #[derive(Debug)]
+    enum Enum {
+        Unit,
+        Tuple(#[debug("{:.1}")] f64),
+        Struct {
+            #[debug(skip)]
+            secret: u8,
+            public: u8,
+        },
+    }
+
+    #[test]
+    fn assert() {
+        let config = Config {
+            name: "db",
+            password: "hunter2",
+            flags: 255,
+            size: 10,
+            buffer: vec![0; 10],
+        };
+        assert_eq!(
+            format!("{config:?}"),
+            r#"Config { name: "db", password: [REDACTED], flags: 0xff, size: 10 bytes, .. }"#,
+        );
+        assert_eq!(
+            format!("{config:#?}"),
+            "Config {\n    \
+                name: \"db\",\n    \
+                password: [REDACTED],\n    \
+                flags: 0xff,\n    \
+                size: 10 bytes,\n    \
+                ..\n\
+            }",
+        );
+        assert_eq!(format!("{:?}", AllSkipped { a: 1 }), "AllSkipped { .. }");
+        assert_eq!(format!("{:?}", Enum::Unit), "Unit");
+        assert_eq!(format!("{:?}", Enum::Tuple(1.25)), "Tuple(1.2)");
+        assert_eq!(
+            format!(
+                "{:?}",
+                Enum::Struct {
+                    secret: 1,
+                    public: 2,
+                },
+            ),
+            "Struct { public: 2, .. }",
+        );
+    }
+
+    // `DebugTuple::finish_non_exhaustive()` requires Rust 1.83.
+    #[rustversion::since(1.83)]
+    mod tuple {
+        use super::Debug;
+
+        #[derive(Debug)]
+        struct Tuple(#[debug(skip)] Vec<u8>, #[debug(redact)] &'static str, i32);
+
+        #[test]
+        fn assert() {
+            assert_eq!(
+                format!("{:?}", Tuple(vec![1], "secret", 3)),
+                "Tuple([REDACTED], 3, ..)",
+            );
+            assert_eq!(
+                format!("{:#?}", Tuple(vec![1], "secret", 3)),
+                "Tuple(\n    [REDACTED],\n    3,\n    ..\n)",
+            );
+        }
+    }
+}
+
+mod generic {
+    use super::Debug;
+
+    struct NoDebug;
+
+    #[derive(Debug)]
+    struct Wrapper<T, U, V> {
+        value: T,
+        #[debug(skip)]
+        skipped: U,
+        #[debug(redact)]
+        redacted: V,
+    }
+
+    #[derive(Debug)]
+    struct Hex<T>(#[debug("{:x}")] T);
+
+    #[derive(Debug)]
+    enum Maybe<T> {
+        Just(Vec<T>),
+        Nothing,
+    }
+
+    #[test]
+    fn assert() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                Wrapper {
+                    value: 1,
+                    skipped: NoDebug,
+                    redacted: NoDebug,
+                },
+            ),
+            "Wrapper { value: 1, redacted: [REDACTED], .. }",
+        );
+        assert_eq!(format!("{:?}", Hex(255)), "Hex(ff)");
+        assert_eq!(format!("{:?}", Maybe::Just(vec![1])), "Just([1])");
+        assert_eq!(format!("{:?}", Maybe::<i32>::Nothing), "Nothing");
+    }
+}
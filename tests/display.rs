@@ -1,12 +1,13 @@
-#![allow(dead_code, unused_imports)]
+#![allow(dead_code, unknown_lints, unused_imports)]
+#![deny(non_local_definitions)]
 
-use std::path::PathBuf;
+use std::{collections::BTreeSet, fmt, path::PathBuf};
 
 // Here just to make sure that this doesn't conflict with
 // the derives in some way
 use std::fmt::Binary;
 
-use derive_more::{Binary, DebugCustom, Display, Octal, UpperHex};
+use derive_more::{Binary, DebugCustom, Display, LowerHex, Octal, UpperHex};
 
 #[derive(Display, Octal, Binary)]
 struct MyInt(i32);
@@ -120,6 +121,355 @@ enum Affix {
 #[display(fmt = "{:?}", self)]
 struct DebugStructAsDisplay;
 
+#[allow(non_upper_case_globals)]
+const gap: usize = 6;
+
+#[derive(Display)]
+#[display("{gaps:?}{name:>gap$}")]
+struct CapturedConst {
+    name: &'static str,
+    gaps: u8,
+}
+
+#[derive(Display)]
+#[display("({}, {})", self.x, y)]
+struct PositionalPoint2D {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Display)]
+#[display("{} is {}", _0, self.sign())]
+struct Signed(i32);
+
+impl Signed {
+    fn sign(&self) -> &str {
+        if self.0 >= 0 {
+            "positive"
+        } else {
+            "negative"
+        }
+    }
+}
+
+#[derive(Display)]
+#[display("{x:>width$}", width = self.width + 1)]
+struct PaddedWidth {
+    x: i32,
+    width: usize,
+}
+
+#[derive(Display)]
+enum PositionalEnum {
+    Unit,
+    #[display("{}-{}", a, b.len())]
+    Named {
+        a: u8,
+        b: Vec<u8>,
+    },
+    #[display("{0:?} {x}", _0, x = _0 * 2)]
+    Unnamed(u8),
+}
+
+#[derive(Display)]
+#[display("Prefix {} suffix")]
+enum PositionalAffix {
+    A(u8),
+    #[display("b {}", _0)]
+    B(u8),
+}
+
+#[derive(Display)]
+#[display("union")]
+union Union {
+    i: u32,
+}
+
+#[derive(DebugCustom)]
+#[debug("Debug {:#x}", _0)]
+struct DebugHex(u8);
+
+fn upper(s: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", s.to_uppercase())
+}
+
+#[derive(Display)]
+#[display("{name}: {price}")]
+struct Item {
+    #[display(with = upper)]
+    name: &'static str,
+    #[display(fmt = "${:.2}")]
+    price: f64,
+}
+
+#[derive(Display)]
+struct Price(#[display("{:.1}")] f64);
+
+#[derive(Display)]
+enum FieldFmtEnum {
+    #[display("{_0} and {}", _0)]
+    A(#[display("<{}>")] u8),
+    B {
+        #[display("{:#x}")]
+        b: u8,
+    },
+}
+
+#[derive(LowerHex)]
+#[lower_hex("{a}/{b}")]
+struct FieldFmtHex {
+    a: u8,
+    #[lower_hex("{:#x}")]
+    b: u8,
+}
+
+#[derive(Display)]
+struct Cached(u64, #[display(forward)] &'static str);
+
+#[derive(Display)]
+enum ForwardEnum {
+    A(#[display(forward)] f64, u8),
+    #[display("b")]
+    B,
+}
+
+#[derive(Display)]
+#[display(rename_all = "snake_case")]
+enum Status {
+    InProgress,
+    HTTPError,
+    #[display(rename = "ok!")]
+    Ok,
+    #[display("failed: {}", _0)]
+    Failed(u8),
+    Other(&'static str),
+}
+
+#[derive(Display)]
+#[display(rename_all = "SCREAMING-KEBAB-CASE")]
+#[display("<{}>")]
+enum RenamedAffix {
+    FirstVariant,
+    #[display(rename = "2nd")]
+    SecondVariant {},
+}
+
+#[derive(UpperHex)]
+#[upper_hex(rename_all = "camelCase")]
+enum RenamedHex {
+    FooBar,
+}
+
+#[derive(Display)]
+#[display(rename = "unit struct")]
+struct RenamedUnit;
+
+#[derive(Display)]
+#[display(rename_all = "snake_case")]
+enum Raw {
+    r#Type,
+    r#LoopKind,
+}
+
+#[derive(Display)]
+struct r#Match;
+
+#[derive(Display)]
+#[display("{_type}::{_variant}")]
+enum Route {
+    Root,
+    Nested(u8),
+    Named { x: i32 },
+}
+
+#[derive(Display)]
+#[display(rename_all = "lowercase")]
+#[display("{_variant}: {}")]
+enum Event {
+    #[display("({x}, {y})")]
+    Click {
+        x: i32,
+        y: i32,
+    },
+    #[display(rename = "key press")]
+    Key(char),
+    #[display("{_0} ({_variant})")]
+    Scroll(i8),
+    Idle,
+}
+
+#[derive(Display)]
+enum Message {
+    #[display("{_variant}({_0}) of {_type}")]
+    Text(&'static str),
+    #[display(rename = "bin")]
+    #[display(alt = "{_variant:?}")]
+    Binary(u8),
+}
+
+#[derive(Display)]
+#[display("{_type} {{ {_variant} }}")]
+struct Braced {
+    _variant: u8,
+}
+
+#[derive(Display)]
+#[display(delimiter = ", ")]
+struct JoinedPoint(i32, i32, i32);
+
+#[derive(Display)]
+#[display(delimiter = "/")]
+struct JoinedPath {
+    #[display("<{}>")]
+    root: &'static str,
+    #[display(join = "/")]
+    segments: Vec<&'static str>,
+}
+
+#[derive(Display)]
+struct Ids(#[display(join = ", ")] BTreeSet<u32>);
+
+#[derive(Display)]
+#[display("ids: [{ids}]")]
+struct CapturedIds {
+    #[display(join = "; ")]
+    ids: &'static [u8],
+}
+
+#[derive(LowerHex)]
+struct Bytes(#[lower_hex(join = ":")] [u8; 3]);
+
+#[derive(Display)]
+#[display(delimiter = " ")]
+enum JoinedEnum {
+    A(u8, u8),
+    #[display(delimiter = "-")]
+    B {
+        a: u8,
+        b: u8,
+    },
+    #[display("c")]
+    C(u8, u8),
+    D,
+}
+
+#[derive(Display)]
+#[display("{name} ({email})")]
+struct User {
+    name: &'static str,
+    #[display(some = "<{}>", none = "no email")]
+    email: Option<&'static str>,
+}
+
+#[derive(Display)]
+struct Limit(#[display(none = "unlimited")] Option<u32>);
+
+#[derive(Display)]
+struct Precise(#[display(none = "-", some = "{:.2}")] Option<f64>);
+
+#[derive(UpperHex)]
+struct OptionalHex(#[upper_hex(none = "?")] Option<u8>);
+
+#[derive(Display)]
+#[display(pad)]
+#[display("{x}x{y}")]
+struct Size {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Display)]
+#[display(pad)]
+enum PaddedStatus {
+    #[display("ok")]
+    Ok,
+    #[display("failed: {_0}")]
+    Failed(&'static str),
+    Pending(u8),
+}
+
+#[derive(LowerHex)]
+#[lower_hex(pad)]
+#[lower_hex("0x{_0:x}")]
+struct Addr(u16);
+
+#[derive(Display)]
+#[display(pad)]
+#[display("{_0}")]
+#[display(alt = "<{_0}>")]
+struct Tag(&'static str);
+
+#[derive(Display)]
+#[display("{x}, {y}")]
+#[display(alt = "Point:\n  x = {x}\n  y = {y}")]
+struct AltPoint {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Display)]
+enum AltError {
+    #[display("not found: {_0}")]
+    #[display(alt = "resource `{_0}` was not found after {} attempts", _1 + 1)]
+    NotFound(&'static str, u8),
+    #[display(alt = "timed out ({_0:?})")]
+    Timeout(u32),
+    Unknown,
+}
+
+#[derive(UpperHex)]
+#[upper_hex(alt = "0x{_0:X}")]
+struct AltHex(u8);
+
+#[derive(Display)]
+#[display(pad)]
+enum PaddedAlt {
+    #[display(alt = "#{_0}")]
+    Id(u8),
+}
+
+#[derive(Display, LowerHex, UpperHex, Binary)]
+#[display(discriminant)]
+#[lower_hex(discriminant)]
+#[upper_hex(discriminant)]
+#[binary(discriminant)]
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    Load = 10,
+    Store,
+    #[display("halt")]
+    Halt = 0xFF,
+}
+
+#[derive(Display)]
+enum Mixed {
+    #[display(discriminant)]
+    A = -1,
+    B,
+}
+
+#[derive(LowerHex, UpperHex)]
+struct Digest(
+    #[lower_hex(bytes)]
+    #[upper_hex(bytes)]
+    [u8; 4],
+);
+
+#[derive(LowerHex)]
+struct Key<'a> {
+    #[lower_hex(bytes)]
+    id: &'a [u8],
+}
+
+#[derive(Display, LowerHex)]
+#[display("payload of {} bytes", data.len())]
+#[lower_hex("{data}")]
+struct Payload {
+    #[lower_hex(bytes)]
+    data: Vec<u8>,
+}
+
 #[test]
 fn check_display() {
     assert_eq!(MyInt(-2).to_string(), "-2");
@@ -158,6 +508,231 @@ fn check_display() {
         "Here's a prefix for things -- false and a suffix"
     );
     assert_eq!(DebugStructAsDisplay.to_string(), "DebugStructAsDisplay");
+    assert_eq!(CapturedConst { name: "a", gaps: 1 }.to_string(), "1     a",);
+    assert_eq!(PositionalPoint2D { x: 3, y: 4 }.to_string(), "(3, 4)");
+    assert_eq!(Signed(-1).to_string(), "-1 is negative");
+    assert_eq!(PaddedWidth { x: 7, width: 2 }.to_string(), "  7");
+    assert_eq!(PositionalEnum::Unit.to_string(), "Unit");
+    assert_eq!(
+        PositionalEnum::Named {
+            a: 1,
+            b: vec![1, 2],
+        }
+        .to_string(),
+        "1-2",
+    );
+    assert_eq!(PositionalEnum::Unnamed(3).to_string(), "3 6");
+    assert_eq!(PositionalAffix::A(1).to_string(), "Prefix 1 suffix");
+    assert_eq!(PositionalAffix::B(2).to_string(), "Prefix b 2 suffix");
+    assert_eq!(Union { i: 1 }.to_string(), "union");
+    assert_eq!(format!("{:?}", DebugHex(255)), "Debug 0xff");
+    assert_eq!(
+        Item {
+            name: "apple",
+            price: 1.5,
+        }
+        .to_string(),
+        "APPLE: $1.50",
+    );
+    assert_eq!(Price(2.25).to_string(), "2.2");
+    assert_eq!(FieldFmtEnum::A(1).to_string(), "<1> and 1");
+    assert_eq!(FieldFmtEnum::B { b: 255 }.to_string(), "0xff");
+    assert_eq!(format!("{:x}", FieldFmtHex { a: 10, b: 11 }), "10/0xb");
+    assert_eq!(format!("{:<4}|", Cached(1, "ab")), "ab  |");
+    assert_eq!(format!("{:.1}", ForwardEnum::A(1.25, 0)), "1.2");
+    assert_eq!(ForwardEnum::B.to_string(), "b");
+    assert_eq!(Status::InProgress.to_string(), "in_progress");
+    assert_eq!(Status::HTTPError.to_string(), "http_error");
+    assert_eq!(Status::Ok.to_string(), "ok!");
+    assert_eq!(Status::Failed(1).to_string(), "failed: 1");
+    assert_eq!(Status::Other("InProgress").to_string(), "InProgress");
+    assert_eq!(RenamedAffix::FirstVariant.to_string(), "<FIRST-VARIANT>");
+    assert_eq!(RenamedAffix::SecondVariant {}.to_string(), "<2nd>");
+    assert_eq!(format!("{:X}", RenamedHex::FooBar), "fooBar");
+    assert_eq!(RenamedUnit.to_string(), "unit struct");
+    assert_eq!(Raw::r#Type.to_string(), "type");
+    assert_eq!(Raw::r#LoopKind.to_string(), "loop_kind");
+    assert_eq!(r#Match.to_string(), "Match");
+    assert_eq!(Route::Root.to_string(), "Route::Root");
+    assert_eq!(Route::Nested(1).to_string(), "Route::Nested");
+    assert_eq!(Route::Named { x: 1 }.to_string(), "Route::Named");
+    assert_eq!(Event::Click { x: 1, y: 2 }.to_string(), "click: (1, 2)");
+    assert_eq!(Event::Key('a').to_string(), "key press: a");
+    assert_eq!(Event::Scroll(-1).to_string(), "scroll: -1 (scroll)");
+    assert_eq!(Event::Idle.to_string(), "idle: idle");
+    assert_eq!(Message::Text("hi").to_string(), "Text(hi) of Message");
+    assert_eq!(Message::Binary(1).to_string(), "1");
+    assert_eq!(format!("{:#}", Message::Binary(1)), "\"bin\"");
+    assert_eq!(Braced { _variant: 7 }.to_string(), "Braced { 7 }");
+    assert_eq!(JoinedPoint(1, 2, 3).to_string(), "1, 2, 3");
+    assert_eq!(
+        JoinedPath {
+            root: "home",
+            segments: vec!["user", "docs"],
+        }
+        .to_string(),
+        "<home>/user/docs",
+    );
+    assert_eq!(Ids([3, 1, 2].into_iter().collect()).to_string(), "1, 2, 3");
+    assert_eq!(Ids(BTreeSet::new()).to_string(), "");
+    assert_eq!(CapturedIds { ids: &[1, 2] }.to_string(), "ids: [1; 2]");
+    assert_eq!(format!("{:x}", Bytes([10, 11, 255])), "a:b:ff");
+    assert_eq!(JoinedEnum::A(1, 2).to_string(), "1 2");
+    assert_eq!(JoinedEnum::B { a: 1, b: 2 }.to_string(), "1-2");
+    assert_eq!(JoinedEnum::C(1, 2).to_string(), "c");
+    assert_eq!(JoinedEnum::D.to_string(), "D");
+    assert_eq!(
+        User {
+            name: "alice",
+            email: Some("a@b.c"),
+        }
+        .to_string(),
+        "alice (<a@b.c>)",
+    );
+    assert_eq!(
+        User {
+            name: "bob",
+            email: None,
+        }
+        .to_string(),
+        "bob (no email)",
+    );
+    assert_eq!(Limit(Some(3)).to_string(), "3");
+    assert_eq!(Limit(None).to_string(), "unlimited");
+    assert_eq!(format!("{:>3}", Limit(Some(3))), "  3");
+    assert_eq!(Precise(Some(1.0)).to_string(), "1.00");
+    assert_eq!(Precise(None).to_string(), "-");
+    assert_eq!(format!("{:X}", OptionalHex(Some(255))), "FF");
+    assert_eq!(format!("{:X}", OptionalHex(None)), "?");
+    let size = Size { x: 640, y: 480 };
+    assert_eq!(size.to_string(), "640x480");
+    assert_eq!(format!("{size:10}|"), "640x480   |");
+    assert_eq!(format!("{size:>10}|"), "   640x480|");
+    assert_eq!(format!("{size:*^10}|"), "*640x480**|");
+    assert_eq!(format!("{size:.3}|"), "640|");
+    assert_eq!(format!("{size:>5.4}|"), " 640x|");
+    assert_eq!(format!("{size:3}|"), "640x480|");
+    assert_eq!(format!("{:8}|", PaddedStatus::Ok), "ok      |");
+    assert_eq!(
+        format!("{:>12}|", PaddedStatus::Failed("io")),
+        "  failed: io|",
+    );
+    assert_eq!(format!("{:<4}|", PaddedStatus::Pending(7)), "7   |");
+    assert_eq!(format!("{:>8x}|", Addr(255)), "    0xff|");
+    assert_eq!(format!("{:>6}|", Tag("br")), "    br|");
+    assert_eq!(format!("{:>#6}|", Tag("br")), "  <br>|");
+    assert_eq!(format!("{:#.3}|", Tag("br")), "<br|");
+    let point = AltPoint { x: 1, y: 2 };
+    assert_eq!(format!("{point}"), "1, 2");
+    assert_eq!(format!("{point:#}"), "Point:\n  x = 1\n  y = 2");
+    let err = AltError::NotFound("db", 2);
+    assert_eq!(format!("{err}"), "not found: db");
+    assert_eq!(
+        format!("{err:#}"),
+        "resource `db` was not found after 3 attempts",
+    );
+    assert_eq!(format!("{}", AltError::Timeout(30)), "30");
+    assert_eq!(format!("{:#}", AltError::Timeout(30)), "timed out (30)");
+    assert_eq!(format!("{}", AltError::Unknown), "Unknown");
+    assert_eq!(format!("{:#}", AltError::Unknown), "Unknown");
+    assert_eq!(format!("{:X}", AltHex(255)), "FF");
+    assert_eq!(format!("{:#X}", AltHex(255)), "0xFF");
+    assert_eq!(format!("{:<4}|", PaddedAlt::Id(7)), "7   |");
+    assert_eq!(format!("{:<#4}|", PaddedAlt::Id(7)), "#7  |");
+    assert_eq!(Opcode::Nop.to_string(), "0");
+    assert_eq!(Opcode::Load.to_string(), "10");
+    assert_eq!(Opcode::Store.to_string(), "11");
+    assert_eq!(Opcode::Halt.to_string(), "halt");
+    assert_eq!(format!("{:03}", Opcode::Load), "010");
+    assert_eq!(format!("{:x}", Opcode::Halt), "ff");
+    assert_eq!(format!("{:#X}", Opcode::Store), "0xB");
+    assert_eq!(format!("{:08b}", Opcode::Load), "00001010");
+    assert_eq!(Mixed::A.to_string(), "-1");
+    assert_eq!(Mixed::B.to_string(), "B");
+    let digest = Digest([0xde, 0xad, 0x0b, 0xef]);
+    assert_eq!(format!("{digest:x}"), "dead0bef");
+    assert_eq!(format!("{digest:X}"), "DEAD0BEF");
+    assert_eq!(format!("{digest:#x}"), "0xdead0bef");
+    assert_eq!(format!("{digest:#X}"), "0xDEAD0BEF");
+    assert_eq!(format!("{digest:12x}"), "0000dead0bef");
+    assert_eq!(format!("{digest:#12x}"), "0x00dead0bef");
+    assert_eq!(format!("{digest:4x}"), "dead0bef");
+    assert_eq!(format!("{:x}", Key { id: &[1, 2] }), "0102");
+    assert_eq!(format!("{:x}", Key { id: &[] }), "");
+    let payload = Payload {
+        data: vec![0xca, 0xfe],
+    };
+    assert_eq!(payload.to_string(), "payload of 2 bytes");
+    assert_eq!(format!("{payload:x}"), "cafe");
+}
+
+#[cfg(feature = "std")]
+mod path {
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+        path::{self, Path, PathBuf},
+    };
+
+    use derive_more::Display;
+
+    #[derive(Display)]
+    struct Config(PathBuf);
+
+    #[derive(Display)]
+    struct Borrowed<'a>(&'a Path);
+
+    #[derive(Display)]
+    #[display(delimiter = " -> ")]
+    struct Link(path::PathBuf, std::path::PathBuf);
+
+    #[derive(Display)]
+    enum Arg<'a> {
+        Os(OsString),
+        OsRef(&'a OsStr),
+        #[display("{path:?} ({len})")]
+        File {
+            path: PathBuf,
+            len: usize,
+        },
+        #[display("[{_0:>8}]")]
+        Padded(PathBuf),
+    }
+
+    #[derive(Display)]
+    #[display("{name} at {location}")]
+    struct Module<'a> {
+        name: &'a str,
+        #[display(path)]
+        location: Cow<'a, Path>,
+    }
+
+    #[test]
+    fn assert() {
+        assert_eq!(Config("/etc/hosts".into()).to_string(), "/etc/hosts");
+        assert_eq!(format!("{:>6}", Config("a/b".into())), "   a/b");
+        assert_eq!(Borrowed(Path::new("src")).to_string(), "src");
+        assert_eq!(Link("a".into(), "b".into()).to_string(), "a -> b");
+        assert_eq!(Arg::Os("x".into()).to_string(), "x");
+        assert_eq!(Arg::OsRef(OsStr::new("y")).to_string(), "y");
+        assert_eq!(
+            Arg::File {
+                path: "main.rs".into(),
+                len: 3,
+            }
+            .to_string(),
+            "\"main.rs\" (3)",
+        );
+        assert_eq!(Arg::Padded("lib.rs".into()).to_string(), "[  lib.rs]");
+        assert_eq!(
+            Module {
+                name: "core",
+                location: Cow::Borrowed(Path::new("src/core")),
+            }
+            .to_string(),
+            "core at src/core",
+        );
+    }
 }
 
 #[test]
@@ -168,7 +743,9 @@ fn empty_enum_impls_display() {
 }
 
 mod generic {
-    use derive_more::Display;
+    use std::{fmt, marker::PhantomData};
+
+    use derive_more::{DebugCustom, Display, LowerHex};
 
     #[derive(Display)]
     #[display(fmt = "Generic {}", field)]
@@ -339,6 +916,126 @@ mod generic {
         assert_eq!(s.to_string(), "12");
     }
 
+    #[derive(Display)]
+    #[display("{} {}", self.a, b)]
+    struct PositionalGenericStruct<A, B> {
+        a: A,
+        b: B,
+    }
+    #[test]
+    fn positional_generic_struct() {
+        let s = PositionalGenericStruct { a: 1, b: "two" };
+        assert_eq!(s.to_string(), "1 two");
+    }
+
+    #[derive(DebugCustom)]
+    #[debug("{a} {b}")]
+    #[debug(bound = "B: AsRef<[u8]>")]
+    struct FieldFmtGenericStruct<A, B> {
+        #[debug("{:?}")]
+        a: A,
+        #[debug(with = fmt_len)]
+        b: B,
+    }
+    fn fmt_len<T: AsRef<[u8]>>(b: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "len={}", b.as_ref().len())
+    }
+    #[test]
+    fn field_fmt_generic_struct() {
+        let s = FieldFmtGenericStruct {
+            a: "a",
+            b: vec![1, 2, 3],
+        };
+        assert_eq!(format!("{s:?}"), "\"a\" len=3");
+    }
+
+    #[derive(Display)]
+    struct FieldFmtGenericNewtype<T>(#[display("{:e}")] T);
+    #[test]
+    fn field_fmt_generic_newtype() {
+        assert_eq!(FieldFmtGenericNewtype(1500.0).to_string(), "1.5e3");
+    }
+
+    struct NoDisplay;
+
+    #[derive(Display, LowerHex)]
+    struct ForwardMarker<T> {
+        #[display(forward)]
+        #[lower_hex(forward)]
+        id: u32,
+        _marker: PhantomData<T>,
+    }
+    #[test]
+    fn forward_marker() {
+        let s = ForwardMarker::<NoDisplay> {
+            id: 42,
+            _marker: PhantomData,
+        };
+        assert_eq!(s.to_string(), "42");
+        assert_eq!(format!("{s:>5}"), "   42");
+        assert_eq!(format!("{s:#06x}"), "0x002a");
+    }
+
+    #[derive(Display)]
+    struct ForwardGenericStruct<T, U> {
+        #[display(forward)]
+        value: T,
+        hash: U,
+    }
+    #[test]
+    fn forward_generic_struct() {
+        let s = ForwardGenericStruct {
+            value: 7,
+            hash: NoDisplay,
+        };
+        assert_eq!(format!("{s:03}"), "007");
+    }
+
+    #[derive(Display)]
+    #[display(delimiter = " | ")]
+    struct JoinedGenericStruct<T, U> {
+        first: T,
+        #[display(join = ",")]
+        rest: Vec<U>,
+    }
+    #[test]
+    fn joined_generic_struct() {
+        let s = JoinedGenericStruct {
+            first: 'x',
+            rest: vec![1.5, 2.5],
+        };
+        assert_eq!(s.to_string(), "x | 1.5,2.5");
+    }
+
+    #[derive(Display)]
+    #[display("{value}")]
+    struct OptionalGenericStruct<T> {
+        #[display(some = "{}!")]
+        value: Option<T>,
+    }
+    #[test]
+    fn optional_generic_struct() {
+        assert_eq!(OptionalGenericStruct { value: Some(1) }.to_string(), "1!");
+        assert_eq!(OptionalGenericStruct::<i32> { value: None }.to_string(), "");
+    }
+
+    #[derive(Display)]
+    struct OptionalGenericNewtype<T>(#[display(none = "none")] Option<T>);
+    #[test]
+    fn optional_generic_newtype() {
+        assert_eq!(OptionalGenericNewtype(Some("a")).to_string(), "a");
+        assert_eq!(OptionalGenericNewtype::<u8>(None).to_string(), "none");
+    }
+
+    #[derive(Display)]
+    #[display(alt = "Wrapper({_0})")]
+    struct AltGenericNewtype<T>(T);
+    #[test]
+    fn alt_generic_newtype() {
+        assert_eq!(format!("{}", AltGenericNewtype(1)), "1");
+        assert_eq!(format!("{:#}", AltGenericNewtype(1)), "Wrapper(1)");
+    }
+
     mod associated_type_field_enumerator {
         use super::*;
 
@@ -462,6 +1159,8 @@ mod generic {
     }
 
     mod bound {
+        use std::fmt;
+
         use super::*;
 
         #[test]
@@ -567,5 +1266,54 @@ mod generic {
             let s = Struct(10, 20);
             assert_eq!(s.to_string(), "WHAT 10 EVER 20");
         }
+
+        #[test]
+        fn typed() {
+            trait Trait1 {
+                fn function1(&self) -> &'static str;
+            }
+
+            impl Trait1 for i32 {
+                fn function1(&self) -> &'static str {
+                    "WHAT"
+                }
+            }
+
+            #[derive(Display)]
+            #[display(bound(T1: Trait1 + fmt::Display, T2: fmt::Display))]
+            #[display("{} {_0} {_1}", _0.function1())]
+            struct Struct<T1, T2>(T1, T2);
+
+            let s = Struct(10, 20);
+            assert_eq!(s.to_string(), "WHAT 10 20");
+        }
+
+        #[test]
+        fn typed_associated_type() {
+            #[derive(Display)]
+            #[display(bound(T: Iterator + Clone, T::Item: fmt::Display))]
+            #[display("{}", _0.clone().next().unwrap())]
+            struct Struct<T>(T);
+
+            let s = Struct([1, 2].into_iter());
+            assert_eq!(s.to_string(), "1");
+        }
+
+        #[test]
+        fn typed_lifetimes_and_hrtb() {
+            #[derive(Display)]
+            #[display(bound('b: 'a, for<'c> &'c T: IntoIterator, T: fmt::Debug))]
+            #[display("{:?} {}", a, b)]
+            struct Struct<'a, 'b, T> {
+                a: &'a T,
+                b: &'b str,
+            }
+
+            let s = Struct {
+                a: &[1, 2],
+                b: "items",
+            };
+            assert_eq!(s.to_string(), "[1, 2] items");
+        }
     }
 }
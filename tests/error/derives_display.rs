@@ -0,0 +1,117 @@
+#![allow(dead_code)]
+use super::*;
+
+#[test]
+fn struct_with_fmt() {
+    #[derive(Debug, Error)]
+    #[error("cannot open `{path}`: {source}")]
+    struct TestErr {
+        path: &'static str,
+        source: SimpleErr,
+    }
+
+    let err = TestErr {
+        path: "a.txt",
+        source: SimpleErr,
+    };
+    assert_eq!(err.to_string(), "cannot open `a.txt`: ");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn tuple_struct_with_args() {
+    #[derive(Debug, Error)]
+    #[error("code {}", _0 + 1)]
+    struct TestErr(#[error(not(source))] i32);
+
+    assert_eq!(TestErr(41).to_string(), "code 42");
+    assert!(TestErr(41).source().is_none());
+}
+
+#[test]
+fn enum_with_fmt() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("not found")]
+        NotFound,
+        #[error("invalid {field}")]
+        Invalid {
+            #[error(ignore)]
+            field: &'static str,
+        },
+        Io(#[error(not(source))] &'static str),
+        #[error(ignore)]
+        Wrapped(SimpleErr),
+    }
+
+    assert_eq!(TestErr::NotFound.to_string(), "not found");
+    assert_eq!(
+        TestErr::Invalid { field: "name" }.to_string(),
+        "invalid name"
+    );
+    assert_eq!(TestErr::Io("broken pipe").to_string(), "broken pipe");
+    assert_eq!(TestErr::Wrapped(SimpleErr).to_string(), "");
+    assert!(TestErr::Wrapped(SimpleErr).source().is_none());
+}
+
+#[test]
+fn generic_enum_with_fmt() {
+    #[derive(Debug, Error)]
+    enum TestErr<E> {
+        #[error("wrapped: {source}")]
+        Wrapped { source: E },
+        #[error("other")]
+        Other,
+    }
+
+    let err = TestErr::Wrapped { source: SimpleErr };
+    assert_eq!(err.to_string(), "wrapped: ");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert_eq!(TestErr::<SimpleErr>::Other.to_string(), "other");
+}
+
+#[test]
+fn tuple_struct_with_positional_placeholders() {
+    #[derive(Debug, Error)]
+    #[error("code {0} ({1:?}) {{0}}")]
+    struct TestErr(#[error(not(source))] i32, &'static str);
+
+    #[derive(Debug, Error)]
+    #[error("code {0}", _0 + 1)]
+    struct WithArgs(#[error(not(source))] i32);
+
+    assert_eq!(TestErr(42, "a").to_string(), "code 42 (\"a\") {0}");
+    assert_eq!(WithArgs(41).to_string(), "code 42");
+}
+
+#[test]
+fn enum_with_positional_placeholders() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("invalid {0}")]
+        Invalid(#[error(not(source))] u8),
+        #[error("wrapped: {0}")]
+        Wrapped(SimpleErr),
+    }
+
+    assert_eq!(TestErr::Invalid(1).to_string(), "invalid 1");
+    assert_eq!(TestErr::Wrapped(SimpleErr).to_string(), "wrapped: ");
+}
+
+#[test]
+fn keeps_display_attributes() {
+    #[derive(Debug, Error)]
+    #[display(rename_all = "snake_case")]
+    enum TestErr<T> {
+        #[error("invalid {_0}")]
+        Invalid(#[error(not(source))] u8),
+        NotFound,
+        #[display(bound(T: ::core::fmt::Display))]
+        #[display("{_0}!")]
+        Other(#[error(not(source))] T),
+    }
+
+    assert_eq!(TestErr::<u8>::Invalid(1).to_string(), "invalid 1");
+    assert_eq!(TestErr::<u8>::NotFound.to_string(), "not_found");
+    assert_eq!(TestErr::Other(2).to_string(), "2!");
+}
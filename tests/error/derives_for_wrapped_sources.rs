@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+use std::{rc::Rc, sync::Arc};
+
+use super::*;
+
+#[test]
+fn named_option() {
+    derive_display!(TestErr);
+    #[derive(Default, Debug, Error)]
+    struct TestErr {
+        source: Option<SimpleErr>,
+    }
+
+    assert!(TestErr::default().source().is_none());
+
+    let err = TestErr {
+        source: Some(SimpleErr),
+    };
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn unnamed_boxed_dyn() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr(Box<dyn Error + Send + Sync>);
+
+    let err = TestErr(Box::new(SimpleErr));
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn named_explicit_option_of_box() {
+    derive_display!(TestErr);
+    #[derive(Default, Debug, Error)]
+    struct TestErr {
+        #[error(source)]
+        cause: Option<Box<dyn Error + 'static>>,
+    }
+
+    assert!(TestErr::default().source().is_none());
+
+    let err = TestErr {
+        cause: Some(Box::new(SimpleErr)),
+    };
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn enum_with_arc_and_rc() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    enum TestErr {
+        Shared(Arc<SimpleErr>),
+        Local { source: Rc<SimpleErr> },
+        Maybe { source: Option<Arc<SimpleErr>> },
+    }
+
+    assert!(TestErr::Shared(Arc::new(SimpleErr))
+        .source()
+        .unwrap()
+        .is::<SimpleErr>());
+    assert!(TestErr::Local {
+        source: Rc::new(SimpleErr),
+    }
+    .source()
+    .unwrap()
+    .is::<SimpleErr>());
+    assert!(TestErr::Maybe { source: None }.source().is_none());
+}
+
+#[test]
+fn generic_option_and_box() {
+    derive_display!(TestErr, E);
+    #[derive(Debug, Error)]
+    enum TestErr<E> {
+        Maybe { source: Option<E> },
+        Boxed(Box<E>),
+    }
+
+    let err = TestErr::Maybe {
+        source: Some(SimpleErr),
+    };
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert!(TestErr::<SimpleErr>::Maybe { source: None }
+        .source()
+        .is_none());
+    assert!(TestErr::Boxed(Box::new(SimpleErr))
+        .source()
+        .unwrap()
+        .is::<SimpleErr>());
+}
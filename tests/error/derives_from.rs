@@ -0,0 +1,111 @@
+#![allow(dead_code)]
+use super::*;
+
+#[test]
+fn unnamed_struct() {
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestErr(#[error(from)] SimpleErr);
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn named_struct() {
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestErr {
+        #[error(from)]
+        err: SimpleErr,
+    }
+
+    let err: TestErr = SimpleErr.into();
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn wrapped_source() {
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestErr(#[error(from)] Box<SimpleErr>);
+
+    let err = TestErr::from(Box::new(SimpleErr));
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn enum_variants() {
+    #[derive(Debug, Error)]
+    #[error("inner")]
+    struct Inner;
+
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("simple")]
+        Simple(#[error(from)] SimpleErr),
+        #[error("inner")]
+        Inner {
+            #[error(from)]
+            source: Inner,
+        },
+        #[error(transparent)]
+        Io(#[error(from)] std::io::Error),
+        #[error("other")]
+        Other(SimpleErr),
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert!(matches!(err, TestErr::Simple(_)));
+    assert!(err.source().unwrap().is::<SimpleErr>());
+
+    let err = TestErr::from(Inner);
+    assert!(matches!(err, TestErr::Inner { .. }));
+    assert!(err.source().unwrap().is::<Inner>());
+
+    let err = TestErr::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+    assert!(matches!(err, TestErr::Io(_)));
+    assert_eq!(err.to_string(), "entity not found");
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestErr<E> {
+        #[error(from)]
+        source: E,
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn captures_unprovided_backtraces() {
+    use std::backtrace::{Backtrace, BacktraceStatus};
+
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestErr {
+        #[error(from)]
+        source: SimpleErr,
+        #[error(not(backtrace))]
+        backtrace: Backtrace,
+    }
+
+    #[derive(Debug, Error)]
+    #[error("test")]
+    struct TestOptionalErr(
+        #[error(from)] SimpleErr,
+        #[error(not(backtrace))] Option<Backtrace>,
+    );
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    let captured = err.backtrace.status() == BacktraceStatus::Captured;
+
+    let err = TestOptionalErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert_eq!(err.1.is_some(), captured);
+}
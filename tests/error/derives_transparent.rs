@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+use super::*;
+
+#[derive(Debug, Error)]
+#[error("inner")]
+struct Inner {
+    source: SimpleErr,
+}
+
+#[test]
+fn unnamed_struct() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr(Inner);
+
+    let err = TestErr(Inner { source: SimpleErr });
+    assert_eq!(err.to_string(), "inner");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn named_struct() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr {
+        inner: Inner,
+    }
+
+    let err = TestErr {
+        inner: Inner { source: SimpleErr },
+    };
+    assert_eq!(err.to_string(), "inner");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
+
+#[test]
+fn enum_variants() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("wrapped")]
+        Wrapped(Inner),
+        #[error(transparent)]
+        Other(Inner),
+        #[error(transparent)]
+        Simple { err: SimpleErr },
+    }
+
+    let wrapped = TestErr::Wrapped(Inner { source: SimpleErr });
+    assert_eq!(wrapped.to_string(), "wrapped");
+    assert!(wrapped.source().unwrap().is::<Inner>());
+
+    let other = TestErr::Other(Inner { source: SimpleErr });
+    assert_eq!(other.to_string(), "inner");
+    assert!(other.source().unwrap().is::<SimpleErr>());
+
+    let simple = TestErr::Simple { err: SimpleErr };
+    assert_eq!(simple.to_string(), "");
+    assert!(simple.source().is_none());
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Debug, Error)]
+    enum TestErr<E> {
+        #[error(transparent)]
+        Other(E),
+        #[error("unit")]
+        Unit,
+    }
+
+    let err = TestErr::Other(Inner { source: SimpleErr });
+    assert_eq!(err.to_string(), "inner");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert!(TestErr::<Inner>::Unit.source().is_none());
+}
+
+#[test]
+fn boxed_dyn_error() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("wrapped")]
+        Wrapped(Inner),
+        #[error(transparent)]
+        Other(Box<dyn Error + Send + Sync>),
+    }
+
+    let err = TestErr::Other(Box::new(Inner { source: SimpleErr }));
+    assert_eq!(err.to_string(), "inner");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+
+    let err = TestErr::Other("plain message".into());
+    assert_eq!(err.to_string(), "plain message");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn boxed_dyn_error_struct() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr(std::sync::Arc<dyn Error + Send + Sync>);
+
+    let err = TestErr(std::sync::Arc::new(Inner { source: SimpleErr }));
+    assert_eq!(err.to_string(), "inner");
+    assert!(err.source().unwrap().is::<SimpleErr>());
+}
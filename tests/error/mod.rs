@@ -45,10 +45,14 @@ macro_rules! derive_display {
     };
 }
 
+mod derives_display;
 mod derives_for_enums_with_source;
 mod derives_for_generic_enums_with_source;
 mod derives_for_generic_structs_with_source;
 mod derives_for_structs_with_source;
+mod derives_for_wrapped_sources;
+mod derives_from;
+mod derives_transparent;
 
 #[cfg(nightly)]
 mod nightly;
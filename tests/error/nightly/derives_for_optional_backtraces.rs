@@ -0,0 +1,127 @@
+#![allow(dead_code)]
+
+use std::{backtrace::BacktraceStatus, error};
+
+use super::*;
+
+#[test]
+fn named_implicit_by_field_name() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        backtrace: Option<Backtrace>,
+        field: i32,
+    }
+
+    let err = TestErr {
+        backtrace: None,
+        field: 0,
+    };
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+
+    let err = TestErr {
+        backtrace: Some(Backtrace::force_capture()),
+        field: 0,
+    };
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+    assert_bt!(==, err, err.backtrace.as_ref().unwrap());
+}
+
+#[test]
+fn named_implicit_by_field_type() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        implicit_backtrace: Option<Backtrace>,
+        field: i32,
+    }
+
+    let err = TestErr {
+        implicit_backtrace: Some(Backtrace::force_capture()),
+        field: 0,
+    };
+    assert_bt!(==, err, err.implicit_backtrace.as_ref().unwrap());
+}
+
+#[test]
+fn unnamed_implicit_with_source() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr(BacktraceErr, Option<Backtrace>);
+
+    let err = TestErr(BacktraceErr::default(), None);
+    assert!(err.source().is_some());
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert_bt!(==, err, err.0.backtrace);
+
+    let err = TestErr(BacktraceErr::default(), Some(Backtrace::force_capture()));
+    assert_bt!(==, err, err.1.as_ref().unwrap());
+    assert_bt!(!=, err, err.0.backtrace);
+}
+
+#[test]
+fn named_explicit() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        #[error(backtrace)]
+        trace: Option<std::backtrace::Backtrace>,
+    }
+
+    let err = TestErr { trace: None };
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+}
+
+#[test]
+fn enum_variants() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    enum TestErr {
+        Unit,
+        Named {
+            source: SimpleErr,
+            backtrace: Option<Backtrace>,
+        },
+        Unnamed(Option<Backtrace>, #[error(not(source))] i32),
+    }
+
+    assert!(error::request_ref::<Backtrace>(&TestErr::Unit).is_none());
+
+    let err = TestErr::Named {
+        source: SimpleErr,
+        backtrace: None,
+    };
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+
+    let err = TestErr::Named {
+        source: SimpleErr,
+        backtrace: Some(Backtrace::force_capture()),
+    };
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+
+    let err = TestErr::Unnamed(Some(Backtrace::force_capture()), 0);
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+    let err = TestErr::Unnamed(None, 0);
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+}
+
+#[test]
+fn from_captures() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        #[error(from)]
+        source: SimpleErr,
+        backtrace: Option<Backtrace>,
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert_eq!(
+        err.backtrace.is_some(),
+        Backtrace::capture().status() == BacktraceStatus::Captured,
+    );
+    assert_eq!(
+        error::request_ref::<Backtrace>(&err).is_some(),
+        err.backtrace.is_some(),
+    );
+}
@@ -0,0 +1,180 @@
+#![allow(dead_code)]
+
+use std::error;
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct StatusCode(u16);
+
+#[derive(Debug, PartialEq)]
+struct RequestId(String);
+
+#[test]
+fn named_struct() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        #[error(provide)]
+        status: StatusCode,
+        #[error(provide(ref))]
+        request_id: RequestId,
+        field: i32,
+    }
+
+    let err = TestErr {
+        status: StatusCode(404),
+        request_id: RequestId("abc".into()),
+        field: 0,
+    };
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(404))
+    );
+    assert_eq!(
+        error::request_ref::<RequestId>(&err),
+        Some(&RequestId("abc".into())),
+    );
+    assert!(error::request_ref::<StatusCode>(&err).is_none());
+    assert!(error::request_value::<i32>(&err).is_none());
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+}
+
+#[test]
+fn unnamed_struct() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr(
+        #[error(provide)] StatusCode,
+        #[error(provide(ref))] RequestId,
+    );
+
+    let err = TestErr(StatusCode(500), RequestId("abc".into()));
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(500))
+    );
+    assert_eq!(
+        error::request_ref::<RequestId>(&err),
+        Some(&RequestId("abc".into())),
+    );
+}
+
+#[test]
+fn with_source_and_backtrace() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        source: BacktraceErr,
+        backtrace: Backtrace,
+        #[error(provide)]
+        status: StatusCode,
+    }
+
+    let err = TestErr {
+        source: BacktraceErr::default(),
+        backtrace: Backtrace::force_capture(),
+        status: StatusCode(400),
+    };
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(400))
+    );
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert_bt!(==, err);
+    assert_bt!(!=, err, err.source.backtrace);
+}
+
+#[test]
+fn forwards_to_source() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        source: BacktraceErr,
+        #[error(provide)]
+        status: StatusCode,
+    }
+
+    let err = TestErr {
+        source: BacktraceErr::default(),
+        status: StatusCode(400),
+    };
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(400))
+    );
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert_bt!(==, err, err.source.backtrace);
+}
+
+#[test]
+fn enum_variants() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    enum TestErr {
+        Unit,
+        Status(#[error(provide, not(source))] StatusCode),
+        Named {
+            source: BacktraceErr,
+            #[error(provide(ref))]
+            request_id: RequestId,
+        },
+        Backtraced {
+            backtrace: Backtrace,
+            #[error(provide)]
+            status: StatusCode,
+            #[error(provide(ref))]
+            request_id: RequestId,
+        },
+    }
+
+    let err = TestErr::Unit;
+    assert!(error::request_value::<StatusCode>(&err).is_none());
+
+    let err = TestErr::Status(StatusCode(401));
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(401))
+    );
+    assert!(error::request_ref::<RequestId>(&err).is_none());
+
+    let err = TestErr::Named {
+        source: BacktraceErr::default(),
+        request_id: RequestId("abc".into()),
+    };
+    assert_eq!(
+        error::request_ref::<RequestId>(&err),
+        Some(&RequestId("abc".into())),
+    );
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+
+    let err = TestErr::Backtraced {
+        backtrace: Backtrace::force_capture(),
+        status: StatusCode(403),
+        request_id: RequestId("def".into()),
+    };
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(403))
+    );
+    assert_eq!(
+        error::request_ref::<RequestId>(&err),
+        Some(&RequestId("def".into())),
+    );
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+}
+
+#[test]
+fn generic_enum() {
+    derive_display!(TestErr, T);
+    #[derive(Debug, Error)]
+    enum TestErr<T> {
+        Provided(#[error(provide)] T, #[error(not(source))] i32),
+    }
+
+    let err = TestErr::Provided(StatusCode(418), 0);
+    assert_eq!(
+        error::request_value::<StatusCode>(&err),
+        Some(StatusCode(418))
+    );
+}
@@ -0,0 +1,70 @@
+#![allow(dead_code)]
+
+use std::error;
+
+use super::*;
+
+#[test]
+fn named_struct() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr {
+        #[error(from)]
+        source: SimpleErr,
+        backtrace: Backtrace,
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+    assert_bt!(==, err);
+}
+
+#[test]
+fn unnamed_struct() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    struct TestErr(#[error(from)] SimpleErr, Backtrace);
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+    assert_bt!(==, err, .1);
+}
+
+#[test]
+fn generic_struct() {
+    derive_display!(TestErr, E);
+    #[derive(Debug, Error)]
+    struct TestErr<E> {
+        #[error(from)]
+        err: E,
+        #[error(backtrace)]
+        trace: Backtrace,
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert_bt!(==, err, trace);
+}
+
+#[test]
+fn enum_variants() {
+    derive_display!(TestErr);
+    #[derive(Debug, Error)]
+    enum TestErr {
+        Simple {
+            #[error(from)]
+            source: SimpleErr,
+            backtrace: Backtrace,
+        },
+        Backtraced(#[error(from)] BacktraceErr),
+    }
+
+    let err = TestErr::from(SimpleErr);
+    assert!(err.source().unwrap().is::<SimpleErr>());
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+
+    let err = TestErr::from(BacktraceErr::default());
+    assert!(err.source().unwrap().is::<BacktraceErr>());
+}
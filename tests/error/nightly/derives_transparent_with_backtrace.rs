@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use std::error;
+
+use super::*;
+
+#[test]
+fn unmarked_field() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr(BacktraceErr);
+
+    let err = TestErr(BacktraceErr::default());
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+    assert!(error::request_value::<i32>(&err).is_none());
+}
+
+#[test]
+fn unnamed_struct() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr(#[error(backtrace)] BacktraceErr);
+
+    let err = TestErr(BacktraceErr::default());
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert_bt!(==, err, err.0.backtrace);
+}
+
+#[test]
+fn named_struct() {
+    #[derive(Debug, Error)]
+    #[error(transparent)]
+    struct TestErr {
+        #[error(backtrace)]
+        inner: BacktraceErr,
+    }
+
+    let err = TestErr {
+        inner: BacktraceErr::default(),
+    };
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert_bt!(==, err, err.inner.backtrace);
+}
+
+#[test]
+fn enum_variants() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error(transparent)]
+        Marked(#[error(backtrace)] BacktraceErr),
+        #[error(transparent)]
+        Unmarked(BacktraceErr),
+        #[error(transparent)]
+        Generic(#[error(backtrace, from)] Box<BacktraceErr>),
+    }
+
+    let err = TestErr::Marked(BacktraceErr::default());
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+
+    let err = TestErr::Unmarked(BacktraceErr::default());
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+
+    let err = TestErr::from(Box::new(BacktraceErr::default()));
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+}
+
+#[test]
+fn unmarked_enum_variants() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error(transparent)]
+        Unmarked(BacktraceErr),
+        #[error("unit")]
+        Unit,
+    }
+
+    let err = TestErr::Unmarked(BacktraceErr::default());
+    assert!(error::request_value::<i32>(&err).is_none());
+    assert!(error::request_ref::<Backtrace>(&err).is_none());
+}
+
+#[test]
+fn boxed_dyn_error() {
+    #[derive(Debug, Error)]
+    enum TestErr {
+        #[error("with backtrace")]
+        WithBacktrace(Backtrace),
+        #[error(transparent)]
+        Other(Box<dyn error::Error + Send + Sync>),
+    }
+
+    let err = TestErr::Other(Box::new(BacktraceErr::default()));
+    assert_eq!(error::request_value::<i32>(&err), Some(42));
+    assert!(error::request_ref::<Backtrace>(&err).is_some());
+}
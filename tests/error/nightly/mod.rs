@@ -40,7 +40,7 @@ use super::*;
 /// ```
 macro_rules! assert_bt {
     (@impl $macro:ident, $error:expr, $backtrace:expr) => {
-        $macro!(std::any::request_ref::<Backtrace>(&$error).unwrap().to_string(), $backtrace.to_string());
+        $macro!(std::error::request_ref::<Backtrace>(&$error).unwrap().to_string(), $backtrace.to_string());
     };
     (@expand $macro:ident, $error:expr, .$backtrace:ident) => {
         assert_bt!(@impl $macro, $error, $error.$backtrace())
@@ -68,7 +68,11 @@ macro_rules! assert_bt {
 mod derives_for_enums_with_backtrace;
 mod derives_for_generic_enums_with_backtrace;
 mod derives_for_generic_structs_with_backtrace;
+mod derives_for_optional_backtraces;
+mod derives_for_provided_fields;
 mod derives_for_structs_with_backtrace;
+mod derives_from_with_backtrace;
+mod derives_transparent_with_backtrace;
 
 derive_display!(BacktraceErr);
 #[derive(Debug)]
@@ -85,8 +89,8 @@ impl Default for BacktraceErr {
 }
 
 impl Error for BacktraceErr {
-    fn provide<'a>(&'a self, demand: &mut std::any::Demand<'a>) {
-        demand
+    fn provide<'a>(&'a self, request: &mut std::error::Request<'a>) {
+        request
             .provide_ref::<Backtrace>(&self.backtrace)
             .provide_value::<i32>(42);
     }
@@ -1,3 +1,3 @@
-#![cfg_attr(nightly, feature(error_generic_member_access, provide_any))]
+#![cfg_attr(nightly, feature(error_generic_member_access))]
 
 mod error;
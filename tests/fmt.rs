@@ -0,0 +1,84 @@
+#![no_std]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate alloc;
+
+use core::fmt;
+
+use derive_more::Fmt;
+
+#[derive(Fmt)]
+struct Int(i32);
+
+#[derive(Fmt)]
+struct Id(u32);
+
+#[derive(Fmt)]
+struct Float {
+    value: f64,
+}
+
+#[derive(Fmt)]
+struct Name(&'static str);
+
+#[derive(Fmt)]
+struct Wrapper<T>(T);
+
+#[derive(Fmt)]
+#[fmt(Debug, Pointer)]
+struct Ptr<'a>(&'a u8);
+
+fn assert_display<T: fmt::Display>() {}
+fn assert_binary<T: fmt::Binary>() {}
+
+#[test]
+fn forwards_int() {
+    assert_eq!(format!("{}", Int(-42)), "-42");
+    assert_eq!(format!("{:>5}", Int(42)), "   42");
+    assert_eq!(format!("{:?}", Int(42)), "42");
+    assert_eq!(format!("{:#b}", Int(5)), "0b101");
+    assert_eq!(format!("{:o}", Int(8)), "10");
+    assert_eq!(format!("{:#06x}", Int(255)), "0x00ff");
+    assert_eq!(format!("{:X}", Int(255)), "FF");
+    assert_eq!(format!("{:e}", Int(1500)), "1.5e3");
+    assert_eq!(format!("{:E}", Int(1500)), "1.5E3");
+}
+
+#[test]
+fn forwards_numeric_newtype() {
+    assert_eq!(format!("{}", Id(7)), "7");
+    assert_eq!(format!("{:04}", Id(7)), "0007");
+    assert_eq!(format!("{:#x}", Id(255)), "0xff");
+    assert_eq!(format!("{:o}", Id(8)), "10");
+    assert_eq!(format!("{:e}", Id(1200)), "1.2e3");
+}
+
+#[test]
+fn forwards_float() {
+    let float = Float { value: 1.25 };
+    assert_eq!(format!("{float:.1}"), "1.2");
+    assert_eq!(format!("{float:?}"), "1.25");
+    assert_eq!(format!("{float:e}"), "1.25e0");
+}
+
+#[test]
+fn forwards_str() {
+    assert_eq!(format!("{:-^7}", Name("abc")), "--abc--");
+    assert_eq!(format!("{:?}", Name("abc")), "\"abc\"");
+}
+
+#[test]
+fn forwards_generic() {
+    assert_eq!(format!("{:x}", Wrapper(255u8)), "ff");
+    assert_eq!(format!("{}", Wrapper("a")), "a");
+    assert_display::<Wrapper<&str>>();
+    assert_binary::<Wrapper<u8>>();
+}
+
+#[test]
+fn forwards_pointer() {
+    let value = 1;
+    assert_eq!(format!("{:p}", Ptr(&value)), format!("{:p}", &value));
+    assert_eq!(format!("{:?}", Ptr(&value)), "1");
+}
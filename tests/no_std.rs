@@ -55,6 +55,11 @@ struct Wrapped<T: Clone>(T);
 #[derive(Deref, DerefMut)]
 struct Wrapped2<T: Clone>(T);
 
+#[derive(Display)]
+#[display(pad)]
+#[display("{_0}/{_1}")]
+struct Padded(u8, u8);
+
 #[derive(From, Not, Add, Mul, AddAssign, Constructor, Sum)]
 struct WrappedDouble<T: Clone, U: Clone>(T, U);
 
@@ -74,3 +79,21 @@ enum EnumWithUnit {
     SmallInt(i32),
     Unit,
 }
+
+// `core::error::Error` requires Rust 1.81 or higher.
+#[cfg(not(feature = "std"))]
+mod error {
+    #[derive(Debug, derive_more::Error)]
+    #[error("parse error at {pos}")]
+    struct ParseError {
+        pos: usize,
+    }
+
+    #[derive(Debug, derive_more::Error)]
+    enum ConfigError {
+        #[error("invalid config")]
+        Invalid { source: ParseError },
+        #[error(transparent)]
+        Other(ParseError),
+    }
+}